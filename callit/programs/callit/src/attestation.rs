@@ -0,0 +1,149 @@
+use anchor_lang::prelude::*;
+use crate::state::{Call, CallCategory, Cluster, PriceCondition};
+use crate::errors::ErrorCode;
+
+/// Domain prefix for every resolution message signed by the oracles
+pub const ATTESTATION_DOMAIN: &[u8] = b"CALLIT_RESOLUTION";
+
+pub const ATTESTATION_VERSION: u8 = 4;
+
+/// Tolerated drift between the oracle clock and the validator clock
+pub const MAX_CLOCK_SKEW: i64 = 30;
+
+/// Longest validity window an oracle may give an attestation
+pub const MAX_ATTESTATION_TTL: i64 = 3_600; // 1h
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    CallerWins,
+    CallerLoses,
+    Void,  // Unresolvable (delisted token, no data): everyone is refunded
+}
+
+/// Versioned statement each oracle signs (Ed25519) to resolve a call.
+/// Binding program ID, cluster and call key stops an attestation from being
+/// replayed against another deployment or another call; binding the claim
+/// hash, category and parameters pins down exactly which condition the
+/// oracles evaluated.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct ResolutionAttestation {
+    pub version: u8,
+    pub program_id: Pubkey,
+    pub cluster: Cluster,
+    pub call: Pubkey,
+    pub claim_hash: [u8; 32],
+    pub category: CallCategory,
+    pub price_condition: Option<PriceCondition>,
+    pub outcome: Outcome,
+    pub observed_price: Option<i64>,  // Market cap in USD for MarketCap calls
+    pub issued_at: i64,
+    pub expires_at: i64,
+}
+
+impl ResolutionAttestation {
+    /// Exact bytes the oracles sign: DOMAIN || borsh(attestation)
+    pub fn message(&self) -> Result<Vec<u8>> {
+        let mut message = ATTESTATION_DOMAIN.to_vec();
+        self.serialize(&mut message)?;
+        Ok(message)
+    }
+
+    pub fn validate(
+        &self,
+        call_key: Pubkey,
+        call: &Call,
+        cluster: &Cluster,
+        now: i64,
+    ) -> Result<()> {
+        require!(
+            self.version == ATTESTATION_VERSION,
+            ErrorCode::UnsupportedAttestationVersion
+        );
+
+        require!(
+            self.program_id == crate::ID && self.cluster == *cluster,
+            ErrorCode::AttestationDomainMismatch
+        );
+
+        require!(self.call == call_key, ErrorCode::AttestationCallMismatch);
+
+        require!(
+            self.claim_hash == call.claim.hash,
+            ErrorCode::AttestationClaimMismatch
+        );
+
+        require!(
+            self.category == call.category && self.price_condition == call.price_condition,
+            ErrorCode::AttestationCategoryMismatch
+        );
+
+        // Observations made before the deadline say nothing about the outcome
+        require!(self.issued_at >= call.deadline, ErrorCode::AttestationTooEarly);
+
+        require!(
+            self.issued_at <= now + MAX_CLOCK_SKEW,
+            ErrorCode::AttestationFromFuture
+        );
+
+        require!(
+            self.expires_at > self.issued_at
+                && self.expires_at - self.issued_at <= MAX_ATTESTATION_TTL,
+            ErrorCode::InvalidAttestationExpiry
+        );
+
+        require!(now <= self.expires_at, ErrorCode::AttestationExpired);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{PriceComparator, PriceTiming};
+
+    /// Shared with oracle-service/src/utils/attestation.test.ts; both sides
+    /// must produce these exact bytes
+    const VECTOR_HEX: &str = concat!(
+        "43414c4c49545f5245534f4c5554494f4e",  // domain
+        "04",  // version
+        "24d6356dade4db10c36ae2387a4397fd110e1fef4caaed3f5c4da21c4caf1e41",  // program_id
+        "01",  // cluster
+        "0101010101010101010101010101010101010101010101010101010101010101",  // call
+        "0202020202020202020202020202020202020202020202020202020202020202",  // claim_hash
+        "02fbffffffffffffff40420f0000000000",  // category
+        "010115cd5b0700000000f8ffffff01",  // price_condition
+        "01",  // outcome
+        "01d6ffffffffffffff",  // observed_price
+        "00f1536500000000",  // issued_at
+        "58f3536500000000",  // expires_at
+    );
+
+    fn vector() -> ResolutionAttestation {
+        ResolutionAttestation {
+            version: ATTESTATION_VERSION,
+            program_id: crate::ID,
+            cluster: Cluster::Devnet,
+            call: Pubkey::new_from_array([1; 32]),
+            claim_hash: [2; 32],
+            category: CallCategory::PriceRange { lower: -5, upper: 1_000_000 },
+            price_condition: Some(PriceCondition {
+                comparator: PriceComparator::AtOrBelow,
+                price: 123_456_789,
+                expo: -8,
+                timing: PriceTiming::AnyTimeBefore,
+            }),
+            outcome: Outcome::CallerLoses,
+            observed_price: Some(-42),
+            issued_at: 1_700_000_000,
+            expires_at: 1_700_000_600,
+        }
+    }
+
+    #[test]
+    fn message_matches_shared_vector() {
+        let message = vector().message().unwrap();
+        let hex: String = message.iter().map(|b| format!("{:02x}", b)).collect();
+        assert_eq!(hex, VECTOR_HEX);
+    }
+}
@@ -0,0 +1,251 @@
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+
+// Strict parser for Ed25519 signature-verify precompile instructions.
+//
+// The precompile checks every signature listed in its instruction before the
+// transaction runs, but each entry's signature, public key and message may be
+// read from ANY instruction in the transaction, selected by index. Reading
+// the offsets without the indices would let an attacker point the precompile
+// at bytes it never verified. Only entries whose three indices are
+// `CURRENT_INSTRUCTION` are accepted, so what we read is what was verified.
+//
+//   data = num_signatures: u8 || padding: u8 || offsets[num_signatures] || payload
+//   offsets (14 bytes, little-endian u16s):
+//     signature_offset, signature_instruction_index,
+//     public_key_offset, public_key_instruction_index,
+//     message_data_offset, message_data_size, message_instruction_index
+
+pub const ED25519_PROGRAM_ID: Pubkey = pubkey!("Ed25519SigVerify111111111111111111111111111");
+
+/// Instruction index meaning "inside this Ed25519 instruction"
+pub const CURRENT_INSTRUCTION: u16 = u16::MAX;
+
+const OFFSETS_START: usize = 2;
+const OFFSETS_SIZE: usize = 14;
+const PUBKEY_SIZE: usize = 32;
+const SIGNATURE_SIZE: usize = 64;
+
+/// A signature the precompile verified over `message` for `pubkey`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerifiedSignature<'a> {
+    pub pubkey: Pubkey,
+    pub message: &'a [u8],
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct SignatureOffsets {
+    signature_offset: u16,
+    signature_instruction_index: u16,
+    public_key_offset: u16,
+    public_key_instruction_index: u16,
+    message_data_offset: u16,
+    message_data_size: u16,
+    message_instruction_index: u16,
+}
+
+impl SignatureOffsets {
+    fn read(bytes: &[u8]) -> Self {
+        let field = |i: usize| u16::from_le_bytes([bytes[2 * i], bytes[2 * i + 1]]);
+
+        Self {
+            signature_offset: field(0),
+            signature_instruction_index: field(1),
+            public_key_offset: field(2),
+            public_key_instruction_index: field(3),
+            message_data_offset: field(4),
+            message_data_size: field(5),
+            message_instruction_index: field(6),
+        }
+    }
+
+    fn is_inline(&self) -> bool {
+        self.signature_instruction_index == CURRENT_INSTRUCTION
+            && self.public_key_instruction_index == CURRENT_INSTRUCTION
+            && self.message_instruction_index == CURRENT_INSTRUCTION
+    }
+}
+
+/// Parse the data of an Ed25519 precompile instruction into the signatures
+/// it verified. Fails on malformed data or any entry that reads from
+/// another instruction.
+pub fn parse(data: &[u8]) -> Result<Vec<VerifiedSignature<'_>>> {
+    require!(data.len() >= OFFSETS_START, ErrorCode::InvalidEd25519Instruction);
+
+    let num_signatures = data[0] as usize;
+    require!(num_signatures > 0, ErrorCode::InvalidEd25519Instruction);
+    require!(
+        data.len() >= OFFSETS_START + num_signatures * OFFSETS_SIZE,
+        ErrorCode::InvalidEd25519Instruction
+    );
+
+    (0..num_signatures)
+        .map(|i| {
+            let start = OFFSETS_START + i * OFFSETS_SIZE;
+            let offsets = SignatureOffsets::read(&data[start..start + OFFSETS_SIZE]);
+
+            require!(offsets.is_inline(), ErrorCode::Ed25519OffsetsNotInline);
+
+            // Signature bytes are checked by the precompile; we only need
+            // them to be where the offsets claim
+            slice(data, offsets.signature_offset, SIGNATURE_SIZE)?;
+
+            let pubkey = slice(data, offsets.public_key_offset, PUBKEY_SIZE)?;
+            let message = slice(
+                data,
+                offsets.message_data_offset,
+                offsets.message_data_size as usize
+            )?;
+
+            Ok(VerifiedSignature {
+                pubkey: Pubkey::try_from(pubkey).map_err(|_| ErrorCode::InvalidOraclePubkey)?,
+                message,
+            })
+        })
+        .collect()
+}
+
+fn slice(data: &[u8], offset: u16, len: usize) -> Result<&[u8]> {
+    let start = offset as usize;
+    data.get(start..start + len)
+        .ok_or(ErrorCode::InvalidEd25519Instruction.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MESSAGE: &[u8] = b"CALLIT_RESOLUTION test message";
+
+    fn offsets(
+        signature_offset: u16,
+        public_key_offset: u16,
+        message_data_offset: u16,
+        message_data_size: u16,
+        indices: [u16; 3],
+    ) -> Vec<u8> {
+        [
+            signature_offset,
+            indices[0],
+            public_key_offset,
+            indices[1],
+            message_data_offset,
+            message_data_size,
+            indices[2],
+        ]
+        .iter()
+        .flat_map(|field| field.to_le_bytes())
+        .collect()
+    }
+
+    /// Instruction data laid out like the web3.js / solana-sdk helpers:
+    /// header, offsets, then pubkey, signature and message per entry
+    fn instruction(entries: &[(Pubkey, &[u8])], indices: [u16; 3]) -> Vec<u8> {
+        let payload_start = OFFSETS_START + entries.len() * OFFSETS_SIZE;
+        let mut header = vec![entries.len() as u8, 0];
+        let mut payload = Vec::new();
+
+        for (pubkey, message) in entries {
+            let public_key_offset = (payload_start + payload.len()) as u16;
+            let signature_offset = public_key_offset + PUBKEY_SIZE as u16;
+            let message_data_offset = signature_offset + SIGNATURE_SIZE as u16;
+
+            header.extend(offsets(
+                signature_offset,
+                public_key_offset,
+                message_data_offset,
+                message.len() as u16,
+                indices
+            ));
+            payload.extend(pubkey.to_bytes());
+            payload.extend([7u8; SIGNATURE_SIZE]);
+            payload.extend(*message);
+        }
+
+        header.extend(payload);
+        header
+    }
+
+    fn assert_error(result: Result<Vec<VerifiedSignature>>, expected: ErrorCode) {
+        assert_eq!(result.unwrap_err(), expected.into());
+    }
+
+    #[test]
+    fn parses_inline_signatures() {
+        let oracles = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let data = instruction(
+            &[(oracles[0], MESSAGE), (oracles[1], b"other")],
+            [CURRENT_INSTRUCTION; 3]
+        );
+
+        let signatures = parse(&data).unwrap();
+
+        assert_eq!(
+            signatures,
+            vec![
+                VerifiedSignature { pubkey: oracles[0], message: MESSAGE },
+                VerifiedSignature { pubkey: oracles[1], message: b"other" },
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_data_from_other_instructions() {
+        // Each index on its own pointing at instruction 0 is enough to reject
+        for position in 0..3 {
+            let mut indices = [CURRENT_INSTRUCTION; 3];
+            indices[position] = 0;
+
+            let data = instruction(&[(Pubkey::new_unique(), MESSAGE)], indices);
+            assert_error(parse(&data), ErrorCode::Ed25519OffsetsNotInline);
+        }
+    }
+
+    #[test]
+    fn rejects_one_foreign_entry_among_inline_ones() {
+        let mut data = instruction(
+            &[(Pubkey::new_unique(), MESSAGE), (Pubkey::new_unique(), MESSAGE)],
+            [CURRENT_INSTRUCTION; 3]
+        );
+
+        // message_instruction_index of the second entry
+        let field = OFFSETS_START + OFFSETS_SIZE + 12;
+        data[field..field + 2].copy_from_slice(&1u16.to_le_bytes());
+
+        assert_error(parse(&data), ErrorCode::Ed25519OffsetsNotInline);
+    }
+
+    #[test]
+    fn rejects_truncated_headers() {
+        assert_error(parse(&[]), ErrorCode::InvalidEd25519Instruction);
+        assert_error(parse(&[0, 0]), ErrorCode::InvalidEd25519Instruction);
+
+        // Claims two entries but only carries one set of offsets
+        let mut data = instruction(&[(Pubkey::new_unique(), MESSAGE)], [CURRENT_INSTRUCTION; 3]);
+        data[0] = 2;
+        data.truncate(OFFSETS_START + OFFSETS_SIZE + 10);
+        assert_error(parse(&data), ErrorCode::InvalidEd25519Instruction);
+    }
+
+    #[test]
+    fn rejects_out_of_bounds_offsets() {
+        let data = instruction(&[(Pubkey::new_unique(), MESSAGE)], [CURRENT_INSTRUCTION; 3]);
+        let len = data.len() as u16;
+
+        // (field position within the offsets, bogus value)
+        let cases = [
+            (0, len - 10),  // signature runs past the end
+            (4, len - 10),  // public key runs past the end
+            (8, len),       // message starts past the end
+            (10, u16::MAX), // message size past the end
+        ];
+
+        for (field, value) in cases {
+            let mut data = data.clone();
+            let at = OFFSETS_START + field;
+            data[at..at + 2].copy_from_slice(&value.to_le_bytes());
+
+            assert_error(parse(&data), ErrorCode::InvalidEd25519Instruction);
+        }
+    }
+}
@@ -8,7 +8,7 @@ pub enum ErrorCode {
     #[msg("Invalid call nonce (must be current timestamp ±30s)")]
     InvalidCallNonce,
 
-    #[msg("Claim must be 1-280 characters")]
+    #[msg("Claim title must be 1-80 characters")]
     InvalidClaimLength,
 
     #[msg("Stake amount too low")]
@@ -38,16 +38,43 @@ pub enum ErrorCode {
     #[msg("Target price too close to current price (min 10% distance)")]
     TargetTooClose,
 
+    #[msg("Pyth price feed does not match token address")]
+    PythFeedMismatch,
+
+    #[msg("Pyth confidence interval too wide (>2% of price)")]
+    PythConfidenceTooWide,
+
+    #[msg("Pyth price must be published within 5 minutes after the deadline")]
+    PythPriceOutsideWindow,
+
+    #[msg("Calls decided by the price at the deadline resolve from the price feed, not oracles")]
+    UsePriceFeedResolution,
+
+    #[msg("Only calls decided by the price at the deadline can resolve from the price feed")]
+    NotPriceFeedResolvable,
+
+    #[msg("Call has not been settled yet")]
+    CallNotSettled,
+
+    #[msg("Payout already claimed")]
+    AlreadyClaimed,
+
+    #[msg("Payout must be claimed before closing")]
+    PayoutNotClaimed,
+
+    #[msg("All payouts must be claimed before closing the call")]
+    ClaimsOutstanding,
+
     #[msg("Cannot challenge your own call")]
     CannotChallengeOwnCall,
 
     #[msg("Wallet already participated in this call")]
     AlreadyParticipated,
 
-    #[msg("Maximum challengers reached (50)")]
+    #[msg("Maximum challengers reached (500)")]
     MaxChallengersReached,
 
-    #[msg("Maximum participants reached (20)")]
+    #[msg("Participant page is full")]
     MaxParticipantsReached,
 
     #[msg("Call is not active")]
@@ -62,7 +89,7 @@ pub enum ErrorCode {
     #[msg("Deadline has already passed")]
     DeadlinePassed,
 
-    #[msg("Insufficient oracle signatures (need 2 of 3)")]
+    #[msg("Insufficient oracle signatures for quorum")]
     InsufficientOracleSignatures,
 
     #[msg("Unauthorized oracle signer")]
@@ -97,4 +124,160 @@ pub enum ErrorCode {
 
     #[msg("Auto-refund not yet available (must wait 24h past deadline)")]
     RefundNotYetAvailable,
+
+    #[msg("Signer is not the protocol authority")]
+    Unauthorized,
+
+    #[msg("Insufficient treasury balance (must stay rent-exempt)")]
+    InsufficientTreasuryBalance,
+
+    #[msg("Protocol fee exceeds maximum (1000 bps)")]
+    ProtocolFeeTooHigh,
+
+    #[msg("No authority handover is pending")]
+    NoPendingAuthority,
+
+    #[msg("Signer is not the pending authority")]
+    NotPendingAuthority,
+
+    #[msg("Oracle set must contain 1-10 distinct, non-default keys")]
+    InvalidOracleSet,
+
+    #[msg("No oracle set rotation is pending")]
+    NoPendingOracleSet,
+
+    #[msg("Oracle set rotation timelock has not elapsed (48h)")]
+    OracleSetTimelockActive,
+
+    #[msg("Oracle threshold must be a strict majority of the oracle set")]
+    InvalidOracleThreshold,
+
+    #[msg("Config account is not in the legacy layout")]
+    ConfigAlreadyMigrated,
+
+    #[msg("Unsupported attestation version")]
+    UnsupportedAttestationVersion,
+
+    #[msg("Attestation is for a different program or cluster")]
+    AttestationDomainMismatch,
+
+    #[msg("Attestation is for a different call")]
+    AttestationCallMismatch,
+
+    #[msg("Attestation was issued before the call deadline")]
+    AttestationTooEarly,
+
+    #[msg("Attestation issued_at is in the future")]
+    AttestationFromFuture,
+
+    #[msg("Attestation expiry must be after issued_at and within 1h")]
+    InvalidAttestationExpiry,
+
+    #[msg("Attestation has expired")]
+    AttestationExpired,
+
+    #[msg("Stake mint does not match the call")]
+    StakeMintMismatch,
+
+    #[msg("Token transfer is missing its mint, token accounts or token program")]
+    MissingTokenAccounts,
+
+    #[msg("Stake mint has an unsupported Token-2022 extension")]
+    UnsupportedMintExtension,
+
+    #[msg("Participant page does not belong to this call")]
+    ParticipantPageMismatch,
+
+    #[msg("Call has been challenged and can no longer be cancelled")]
+    CallAlreadyChallenged,
+
+    #[msg("Dispute period has ended")]
+    DisputePeriodEnded,
+
+    #[msg("Dispute period is still open")]
+    DisputePeriodOpen,
+
+    #[msg("Resolution has already been disputed")]
+    AlreadyDisputed,
+
+    #[msg("Resolution is not under dispute")]
+    NotDisputed,
+
+    #[msg("Dispute is awaiting the authority's ruling")]
+    DisputeAwaitingRuling,
+
+    #[msg("Only the caller or a challenger can dispute")]
+    NotParticipant,
+
+    #[msg("Ruling period for this dispute has ended")]
+    RulingPeriodEnded,
+
+    #[msg("Disputer account does not match the resolution")]
+    InvalidDisputerWallet,
+
+    #[msg("Attestation does not match the call's category or parameters")]
+    AttestationCategoryMismatch,
+
+    #[msg("Invalid parameters for this call category")]
+    InvalidCategoryParams,
+
+    #[msg("Price condition is malformed or does not match the feed's exponent")]
+    InvalidPriceCondition,
+
+    #[msg("Price condition is already met")]
+    PriceConditionAlreadyMet,
+
+    #[msg("Claim URI must be 1-200 characters")]
+    InvalidClaimUri,
+
+    #[msg("Claim spec hash is missing")]
+    MissingClaimHash,
+
+    #[msg("Attestation was signed for a different claim spec")]
+    AttestationClaimMismatch,
+
+    #[msg("Malformed Ed25519 signature instruction")]
+    InvalidEd25519Instruction,
+
+    #[msg("Ed25519 signature data must come from its own instruction")]
+    Ed25519OffsetsNotInline,
+
+    #[msg("Invalid oracle bond account")]
+    InvalidOracleBond,
+
+    #[msg("Bond amount must be greater than zero")]
+    InvalidBondAmount,
+
+    #[msg("Oracle bond is below the configured minimum")]
+    InsufficientOracleBond,
+
+    #[msg("Cannot unbond more than is bonded")]
+    UnbondExceedsBond,
+
+    #[msg("Unbonding cooldown has not elapsed")]
+    UnbondCooldownActive,
+
+    #[msg("Nothing to withdraw")]
+    NothingToWithdraw,
+
+    #[msg("Oracle cannot be slashed for this resolution")]
+    NotSlashable,
+
+    #[msg("Oracles behind the overturned outcome must be slashed first")]
+    OraclesNotSlashed,
+
+    #[msg("Invalid oracle rewards account")]
+    InvalidOracleRewardsAccount,
+
+    #[msg("Oracle reward share cannot exceed 100% of the fee")]
+    OracleRewardShareTooHigh,
+
+    #[msg("Pyth price must be the first update published after the deadline")]
+    PythPriceNotFirstAfterDeadline,
+
+    #[msg("Rewards accounts of every resolving oracle are required")]
+    MissingOracleRewardsAccounts,
+
+    #[msg("Calls can only be cancelled within an hour of being made")]
+    CancelWindowClosed,
 }
@@ -0,0 +1,246 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token_2022::spl_token_2022::extension::{
+    transfer_fee::TransferFeeConfig, BaseStateWithExtensions, ExtensionType, StateWithExtensions,
+};
+use anchor_spl::token_2022::spl_token_2022::state::Mint as MintState;
+use anchor_spl::token_2022_extensions::transfer_fee::{
+    harvest_withheld_tokens_to_mint, HarvestWithheldTokensToMint,
+};
+use anchor_spl::token_interface::{
+    self, CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+use crate::errors::ErrorCode;
+
+/// Token-2022 mint extensions a stake mint may carry. Anything else (transfer
+/// hooks, permanent delegates, pausable or default-frozen accounts, ...) could
+/// block or drain the escrow vault.
+const ALLOWED_MINT_EXTENSIONS: [ExtensionType; 9] = [
+    ExtensionType::TransferFeeConfig,
+    ExtensionType::MintCloseAuthority,
+    ExtensionType::InterestBearingConfig,
+    ExtensionType::MetadataPointer,
+    ExtensionType::TokenMetadata,
+    ExtensionType::GroupPointer,
+    ExtensionType::TokenGroup,
+    ExtensionType::GroupMemberPointer,
+    ExtensionType::TokenGroupMember,
+];
+
+/// Reject stake mints whose extensions the escrow cannot safely hold
+pub fn validate_stake_mint(mint: &InterfaceAccount<Mint>) -> Result<()> {
+    let mint_info = mint.to_account_info();
+    let data = mint_info.try_borrow_data()?;
+    let state = StateWithExtensions::<MintState>::unpack(&data)?;
+
+    for extension in state.get_extension_types()? {
+        require!(
+            ALLOWED_MINT_EXTENSIONS.contains(&extension),
+            ErrorCode::UnsupportedMintExtension
+        );
+    }
+
+    Ok(())
+}
+
+/// Token accounts of a call staked in an SPL or Token-2022 mint.
+/// `token_account` is the participant's side of the transfer: the source on
+/// deposit, the destination on payout.
+pub struct StakeToken<'a, 'info> {
+    pub mint: &'a InterfaceAccount<'info, Mint>,
+    pub vault: &'a InterfaceAccount<'info, TokenAccount>,
+    pub token_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub token_program: &'a Interface<'info, TokenInterface>,
+}
+
+impl<'a, 'info> StakeToken<'a, 'info> {
+    /// Token accounts for a call's stake mint: `None` for native SOL calls,
+    /// an error if the call is token-staked and any of them is missing
+    pub fn for_call(
+        stake_mint: Option<Pubkey>,
+        mint: Option<&'a InterfaceAccount<'info, Mint>>,
+        vault: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+        token_account: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+        token_program: Option<&'a Interface<'info, TokenInterface>>,
+    ) -> Result<Option<Self>> {
+        let Some(stake_mint) = stake_mint else {
+            return Ok(None);
+        };
+
+        match (mint, vault, token_account, token_program) {
+            (Some(mint), Some(vault), Some(token_account), Some(token_program)) => {
+                require!(mint.key() == stake_mint, ErrorCode::StakeMintMismatch);
+
+                Ok(Some(Self {
+                    mint,
+                    vault,
+                    token_account,
+                    token_program,
+                }))
+            }
+            _ => Err(ErrorCode::MissingTokenAccounts.into()),
+        }
+    }
+}
+
+/// A call's escrow PDA and, for token-staked calls, the vault it owns.
+/// All stake movements in and out of a call go through here.
+pub struct Escrow<'a, 'info> {
+    pub call_key: Pubkey,
+    pub bump: u8,
+    pub account: &'a SystemAccount<'info>,
+    pub system_program: &'a Program<'info, System>,
+    pub token: Option<StakeToken<'a, 'info>>,
+}
+
+impl<'a, 'info> Escrow<'a, 'info> {
+    /// Move `amount` from `depositor` into escrow. Returns what the escrow
+    /// actually received, which is less than `amount` for Token-2022 mints
+    /// that charge a transfer fee.
+    pub fn deposit(&self, depositor: &Signer<'info>, amount: u64) -> Result<u64> {
+        let Some(token) = &self.token else {
+            self.deposit_lamports(depositor, amount)?;
+            return Ok(amount);
+        };
+
+        let vault_info = token.vault.to_account_info();
+        let before = token_balance(&vault_info)?;
+
+        let transfer = TransferChecked {
+            from: token.token_account.to_account_info(),
+            mint: token.mint.to_account_info(),
+            to: vault_info.clone(),
+            authority: depositor.to_account_info(),
+        };
+
+        token_interface::transfer_checked(
+            CpiContext::new(token.token_program.to_account_info(), transfer),
+            amount,
+            token.mint.decimals
+        )?;
+
+        token_balance(&vault_info)?
+            .checked_sub(before)
+            .ok_or(ErrorCode::ArithmeticOverflow.into())
+    }
+
+    /// Move lamports from `depositor` into the escrow PDA itself
+    pub fn deposit_lamports(&self, depositor: &Signer<'info>, amount: u64) -> Result<()> {
+        let transfer = system_program::Transfer {
+            from: depositor.to_account_info(),
+            to: self.account.to_account_info(),
+        };
+
+        system_program::transfer(
+            CpiContext::new(self.system_program.to_account_info(), transfer),
+            amount
+        )
+    }
+
+    /// Where a payout to `wallet` lands: the wallet itself for native calls,
+    /// `StakeToken::token_account` for token-staked ones
+    pub fn payee(&self, wallet: &AccountInfo<'info>) -> AccountInfo<'info> {
+        match &self.token {
+            Some(token) => token.token_account.to_account_info(),
+            None => wallet.clone(),
+        }
+    }
+
+    /// Pay `amount` out of escrow to `to`: a wallet for native calls, a token
+    /// account of the stake mint otherwise (see `payee`). Any Token-2022
+    /// transfer fee is borne by the recipient.
+    pub fn withdraw(&self, to: &AccountInfo<'info>, amount: u64) -> Result<()> {
+        let Some(token) = &self.token else {
+            return self.withdraw_lamports(to, amount);
+        };
+
+        let transfer = TransferChecked {
+            from: token.vault.to_account_info(),
+            mint: token.mint.to_account_info(),
+            to: to.clone(),
+            authority: self.account.to_account_info(),
+        };
+
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                token.token_program.to_account_info(),
+                transfer,
+                &[&self.signer_seeds()]
+            ),
+            amount,
+            token.mint.decimals
+        )
+    }
+
+    /// Move lamports held by the escrow PDA itself
+    pub fn withdraw_lamports(&self, to: &AccountInfo<'info>, amount: u64) -> Result<()> {
+        let transfer = system_program::Transfer {
+            from: self.account.to_account_info(),
+            to: to.clone(),
+        };
+
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                transfer,
+                &[&self.signer_seeds()]
+            ),
+            amount
+        )
+    }
+
+    /// Sweep leftover tokens to `StakeToken::token_account` and close the
+    /// vault, returning its rent to `rent_recipient`. Returns the tokens swept.
+    pub fn close_vault(&self, rent_recipient: &AccountInfo<'info>) -> Result<u64> {
+        let Some(token) = &self.token else {
+            return Ok(0);
+        };
+
+        let vault_info = token.vault.to_account_info();
+        let swept = token_balance(&vault_info)?;
+
+        if swept > 0 {
+            self.withdraw(&token.token_account.to_account_info(), swept)?;
+        }
+
+        // Token-2022 refuses to close an account still holding withheld
+        // transfer fees; harvesting them to the mint is permissionless
+        let mint_info = token.mint.to_account_info();
+        if token_interface::get_mint_extension_data::<TransferFeeConfig>(&mint_info).is_ok() {
+            harvest_withheld_tokens_to_mint(
+                CpiContext::new(
+                    token.token_program.to_account_info(),
+                    HarvestWithheldTokensToMint {
+                        token_program_id: token.token_program.to_account_info(),
+                        mint: mint_info,
+                    }
+                ),
+                vec![vault_info.clone()]
+            )?;
+        }
+
+        let close = CloseAccount {
+            account: vault_info,
+            destination: rent_recipient.clone(),
+            authority: self.account.to_account_info(),
+        };
+
+        token_interface::close_account(CpiContext::new_with_signer(
+            token.token_program.to_account_info(),
+            close,
+            &[&self.signer_seeds()]
+        ))?;
+
+        Ok(swept)
+    }
+
+    fn signer_seeds(&self) -> [&[u8]; 3] {
+        [b"escrow", self.call_key.as_ref(), std::slice::from_ref(&self.bump)]
+    }
+}
+
+/// Current balance of a token account, read after any CPI that changed it
+fn token_balance(info: &AccountInfo) -> Result<u64> {
+    let data = info.try_borrow_data()?;
+    Ok(TokenAccount::try_deserialize(&mut &data[..])?.amount)
+}
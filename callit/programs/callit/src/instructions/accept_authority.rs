@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+
+/// Step 2 of the authority handover: nominee signs to take over
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    pub new_authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    let pending_authority = config.pending_authority
+        .ok_or(ErrorCode::NoPendingAuthority)?;
+
+    require!(
+        ctx.accounts.new_authority.key() == pending_authority,
+        ErrorCode::NotPendingAuthority
+    );
+
+    let old_authority = config.authority;
+    config.authority = pending_authority;
+    config.pending_authority = None;
+
+    emit!(AuthorityTransferred {
+        old_authority,
+        new_authority: pending_authority,
+    });
+
+    msg!("Authority transferred: {} -> {}", old_authority, pending_authority);
+
+    Ok(())
+}
+
+#[event]
+pub struct AuthorityTransferred {
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
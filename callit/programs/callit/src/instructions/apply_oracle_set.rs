@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct ApplyOracleSet<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ApplyOracleSet>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let clock = Clock::get()?;
+
+    let pending = config.pending_oracle_set
+        .clone()
+        .ok_or(ErrorCode::NoPendingOracleSet)?;
+
+    require!(
+        clock.unix_timestamp >= pending.eta,
+        ErrorCode::OracleSetTimelockActive
+    );
+
+    let old_oracle_signers = std::mem::replace(
+        &mut config.oracle_signers,
+        pending.oracle_signers.clone()
+    );
+    config.oracle_threshold = pending.oracle_threshold;
+    config.pending_oracle_set = None;
+
+    emit!(OracleSetApplied {
+        authority: config.authority,
+        old_oracle_signers,
+        new_oracle_signers: pending.oracle_signers,
+        oracle_threshold: pending.oracle_threshold,
+        applied_at: clock.unix_timestamp,
+    });
+
+    msg!("Oracle set rotated");
+
+    Ok(())
+}
+
+#[event]
+pub struct OracleSetApplied {
+    pub authority: Pubkey,
+    pub old_oracle_signers: Vec<Pubkey>,
+    pub new_oracle_signers: Vec<Pubkey>,
+    pub oracle_threshold: u8,
+    pub applied_at: i64,
+}
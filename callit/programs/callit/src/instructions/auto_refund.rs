@@ -1,13 +1,10 @@
 use anchor_lang::prelude::*;
-use anchor_lang::system_program;
 use crate::state::*;
 use crate::errors::ErrorCode;
 
-/// Permissionless auto-refund after 24h oracle timeout
-/// remaining_accounts layout:
-/// [0..N-1]     = Challenge PDA accounts
-/// [N..2N-1]    = Challenger wallet accounts
-/// [2N]         = Caller wallet account
+/// Permissionless auto-refund after 24h oracle timeout.
+/// Marks the call refunded; every participant then pulls their stake back
+/// with `claim_payout`.
 #[derive(Accounts)]
 pub struct AutoRefundCall<'info> {
     #[account(
@@ -17,22 +14,24 @@ pub struct AutoRefundCall<'info> {
     pub call: Account<'info, Call>,
 
     #[account(
-        mut,
-        seeds = [b"escrow", call.key().as_ref()],
-        bump = call.escrow_bump
+        init_if_needed,
+        payer = triggerer,
+        space = UserProfile::SIZE,
+        seeds = [b"profile", call.caller.as_ref()],
+        bump
     )]
-    pub escrow: SystemAccount<'info>,
+    pub caller_profile: Account<'info, UserProfile>,
 
     /// CHECK: Anyone can trigger (no authorization required)
+    #[account(mut)]
     pub triggerer: Signer<'info>,
 
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, AutoRefundCall<'info>>) -> Result<()> {
+pub fn handler(ctx: Context<AutoRefundCall>) -> Result<()> {
     let call = &mut ctx.accounts.call;
     let clock = Clock::get()?;
-    let n = call.challengers_count as usize;
 
     // Validate 24h timeout
     require!(
@@ -40,94 +39,26 @@ pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, AutoRefundCall<'info>>)
         ErrorCode::RefundNotYetAvailable
     );
 
-    // FIX 13: Properly refund all challengers using remaining_accounts
-    require!(
-        ctx.remaining_accounts.len() == (n * 2) + 1,
-        ErrorCode::InvalidRemainingAccounts
-    );
-
-    let escrow_bump = call.escrow_bump;
-    let call_key = call.key();
-    let seeds = &[b"escrow", call_key.as_ref(), &[escrow_bump]];
-    let signer = &[&seeds[..]];
-
     // ============================================
-    // REFUND EACH CHALLENGER THEIR ACTUAL STAKE
+    // UPDATE STATUS
     // ============================================
 
-    for i in 0..n {
-        // Load challenge PDA to get stake amount
-        let challenge_account = &ctx.remaining_accounts[i];
-        let challenge: Account<Challenge> = Account::try_from(challenge_account)
-            .map_err(|_| ErrorCode::InvalidChallengeAccount)?;
-
-        require!(
-            challenge.call_id == call_key,
-            ErrorCode::ChallengeMismatch
-        );
-
-        let challenger_wallet = &ctx.remaining_accounts[n + i];
-
-        // Verify wallet identity
-        require!(
-            challenger_wallet.key() == challenge.challenger,
-            ErrorCode::InvalidChallengerWallet
-        );
-
-        // Refund full stake
-        let transfer = system_program::Transfer {
-            from: ctx.accounts.escrow.to_account_info(),
-            to: challenger_wallet.to_account_info(),
-        };
-
-        system_program::transfer(
-            CpiContext::new_with_signer(
-                ctx.accounts.system_program.to_account_info(),
-                transfer,
-                signer
-            ),
-            challenge.stake
-        )?;
-
-        msg!("Refunded {} lamports to challenger {}", challenge.stake, challenge.challenger);
-    }
-
-    // ============================================
-    // REFUND CALLER
-    // ============================================
+    call.status = CallStatus::AutoRefunded;
+    call.resolved_at = clock.unix_timestamp;
+    call.protocol_fee = 0;
 
-    let caller_wallet = &ctx.remaining_accounts[n * 2];
+    let caller_profile = &mut ctx.accounts.caller_profile;
+    caller_profile.ensure_initialized(call.caller, ctx.bumps.caller_profile);
+    caller_profile.calls_refunded = caller_profile.calls_refunded.saturating_add(1);
+    caller_profile.updated_at = clock.unix_timestamp;
 
-    // Verify caller identity
-    require!(
-        caller_wallet.key() == call.caller,
-        ErrorCode::InvalidCallerWallet
+    msg!(
+        "Call refunded: {} challengers may claim their stakes",
+        call.challengers_count
     );
 
-    let transfer = system_program::Transfer {
-        from: ctx.accounts.escrow.to_account_info(),
-        to: caller_wallet.to_account_info(),
-    };
-
-    system_program::transfer(
-        CpiContext::new_with_signer(
-            ctx.accounts.system_program.to_account_info(),
-            transfer,
-            signer
-        ),
-        call.stake
-    )?;
-
-    msg!("Refunded {} lamports to caller {}", call.stake, call.caller);
-
-    // ============================================
-    // UPDATE STATUS
-    // ============================================
-
-    call.status = CallStatus::AutoRefunded;
-
     emit!(CallAutoRefunded {
-        call_id: call_key,
+        call_id: call.key(),
         refunded_at: clock.unix_timestamp,
     });
 
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::*;
+use crate::errors::ErrorCode;
+
+/// Add lamports to the oracle's bond. Anyone can bond, but only members of
+/// the oracle set bonded at or above the configured minimum count. The
+/// first bond also opens the oracle's rewards account.
+#[derive(Accounts)]
+pub struct BondOracle<'info> {
+    #[account(
+        init_if_needed,
+        payer = oracle,
+        space = OracleBond::SIZE,
+        seeds = [b"oracle_bond", oracle.key().as_ref()],
+        bump
+    )]
+    pub oracle_bond: Account<'info, OracleBond>,
+
+    #[account(
+        init_if_needed,
+        payer = oracle,
+        space = OracleRewards::SIZE,
+        seeds = [b"oracle_rewards", oracle.key().as_ref()],
+        bump
+    )]
+    pub oracle_rewards: Account<'info, OracleRewards>,
+
+    #[account(mut)]
+    pub oracle: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<BondOracle>, amount: u64) -> Result<()> {
+    require!(amount > 0, ErrorCode::InvalidBondAmount);
+
+    let oracle_bond = &mut ctx.accounts.oracle_bond;
+    oracle_bond.ensure_initialized(ctx.accounts.oracle.key(), ctx.bumps.oracle_bond);
+    ctx.accounts.oracle_rewards
+        .ensure_initialized(ctx.accounts.oracle.key(), ctx.bumps.oracle_rewards);
+
+    let transfer = system_program::Transfer {
+        from: ctx.accounts.oracle.to_account_info(),
+        to: oracle_bond.to_account_info(),
+    };
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            transfer
+        ),
+        amount
+    )?;
+
+    oracle_bond.amount = oracle_bond.amount
+        .checked_add(amount)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    emit!(OracleBonded {
+        oracle: oracle_bond.oracle,
+        amount,
+        total: oracle_bond.amount,
+    });
+
+    msg!("Oracle bond now {} lamports", oracle_bond.amount);
+
+    Ok(())
+}
+
+#[event]
+pub struct OracleBonded {
+    pub oracle: Pubkey,
+    pub amount: u64,
+    pub total: u64,
+}
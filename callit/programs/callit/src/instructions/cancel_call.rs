@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::*;
+use crate::escrow::{Escrow, StakeToken};
+use crate::errors::ErrorCode;
+
+/// Caller withdraws an unchallenged call within `Call::CANCEL_WINDOW` of
+/// making it. The stake, the escrow's rent reserve and (for token calls) the
+/// vault's rent go straight back to the caller; the call itself can then be
+/// reclaimed with `close_call`.
+#[derive(Accounts)]
+pub struct CancelCall<'info> {
+    #[account(
+        mut,
+        has_one = caller @ ErrorCode::InvalidCallerWallet,
+        constraint = call.status == CallStatus::Active @ ErrorCode::CallNotActive
+    )]
+    pub call: Account<'info, Call>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", call.key().as_ref()],
+        bump = call.escrow_bump
+    )]
+    pub escrow: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"profile", caller.key().as_ref()],
+        bump = caller_profile.bump
+    )]
+    pub caller_profile: Account<'info, UserProfile>,
+
+    /// Required for token-staked calls; omit for native SOL. Writable so
+    /// withheld Token-2022 transfer fees can be harvested before closing.
+    #[account(mut)]
+    pub stake_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        mut,
+        associated_token::mint = stake_mint,
+        associated_token::authority = escrow,
+        associated_token::token_program = token_program
+    )]
+    pub escrow_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        token::mint = stake_mint,
+        token::authority = caller,
+        token::token_program = token_program
+    )]
+    pub caller_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+pub fn handler(ctx: Context<CancelCall>) -> Result<()> {
+    let call = &mut ctx.accounts.call;
+    let call_key = call.key();
+    let clock = Clock::get()?;
+
+    // ============================================
+    // VALIDATIONS
+    // ============================================
+
+    require!(call.challengers_count == 0, ErrorCode::CallAlreadyChallenged);
+
+    // Only shortly after creation, long before the outcome is in view
+    require!(
+        clock.unix_timestamp < call.created_at + Call::CANCEL_WINDOW,
+        ErrorCode::CancelWindowClosed
+    );
+
+    // ============================================
+    // REFUND ESCROW
+    // ============================================
+
+    let escrow = Escrow {
+        call_key,
+        bump: call.escrow_bump,
+        account: &ctx.accounts.escrow,
+        system_program: &ctx.accounts.system_program,
+        token: StakeToken::for_call(
+            call.stake_mint,
+            ctx.accounts.stake_mint.as_deref(),
+            ctx.accounts.escrow_vault.as_deref(),
+            ctx.accounts.caller_token_account.as_deref(),
+            ctx.accounts.token_program.as_ref()
+        )?,
+    };
+
+    let caller = ctx.accounts.caller.to_account_info();
+    escrow.close_vault(&caller)?;
+
+    let lamports = ctx.accounts.escrow.lamports();
+    if lamports > 0 {
+        escrow.withdraw_lamports(&caller, lamports)?;
+    }
+
+    // ============================================
+    // UPDATE STATUS
+    // ============================================
+
+    call.status = CallStatus::Cancelled;
+    call.resolved_at = clock.unix_timestamp;
+    call.protocol_fee = 0;
+    call.caller_claimed = true;
+
+    // Cancellations stay on the caller's record alongside the call itself
+    let caller_profile = &mut ctx.accounts.caller_profile;
+    caller_profile.calls_cancelled = caller_profile.calls_cancelled.saturating_add(1);
+    caller_profile.updated_at = clock.unix_timestamp;
+
+    emit!(CallCancelled {
+        call_id: call_key,
+        caller: call.caller,
+        stake_mint: call.stake_mint,
+        refunded: call.stake,
+        cancelled_at: clock.unix_timestamp,
+    });
+
+    msg!("Call cancelled: {}", call_key);
+
+    Ok(())
+}
+
+#[event]
+pub struct CallCancelled {
+    pub call_id: Pubkey,
+    pub caller: Pubkey,
+    pub stake_mint: Option<Pubkey>,
+    pub refunded: u64,
+    pub cancelled_at: i64,
+}
@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
-use anchor_lang::system_program;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use crate::state::*;
+use crate::escrow::{Escrow, StakeToken};
+use crate::payout;
 use crate::errors::ErrorCode;
 
 #[derive(Accounts)]
@@ -36,10 +38,52 @@ pub struct ChallengeCall<'info> {
     #[account(seeds = [b"config"], bump = config.bump)]
     pub config: Account<'info, GlobalConfig>,
 
+    #[account(
+        init_if_needed,
+        payer = challenger,
+        space = UserProfile::SIZE,
+        seeds = [b"profile", challenger.key().as_ref()],
+        bump
+    )]
+    pub challenger_profile: Account<'info, UserProfile>,
+
+    #[account(
+        init_if_needed,
+        payer = challenger,
+        space = ParticipantPage::SIZE,
+        seeds = [
+            b"participants",
+            call.key().as_ref(),
+            &ParticipantPage::page_for(call.challengers_count).to_le_bytes()
+        ],
+        bump
+    )]
+    pub participant_page: Account<'info, ParticipantPage>,
+
+    /// Required for token-staked calls; omit for native SOL
+    pub stake_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        mut,
+        associated_token::mint = stake_mint,
+        associated_token::authority = escrow,
+        associated_token::token_program = token_program
+    )]
+    pub escrow_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        token::mint = stake_mint,
+        token::authority = challenger,
+        token::token_program = token_program
+    )]
+    pub challenger_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
     #[account(mut)]
     pub challenger: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
 }
 
 pub fn handler(
@@ -64,19 +108,10 @@ pub fn handler(
         ErrorCode::CannotChallengeOwnCall
     );
 
-    // Check if already participated
-    for participant in call.participants.iter() {
-        if *participant == ctx.accounts.challenger.key() {
-            return Err(ErrorCode::AlreadyParticipated.into());
-        }
-        if *participant == Pubkey::default() {
-            break; // Reached empty slots
-        }
-    }
-
-    // Check max challengers (50)
+    // Check max challengers. Duplicates need no scan: the Challenge PDA's
+    // `init` already fails for a wallet that has challenged this call
     require!(
-        call.challengers_count < 50,
+        call.challengers_count < Call::MAX_CHALLENGERS,
         ErrorCode::MaxChallengersReached
     );
 
@@ -87,25 +122,32 @@ pub fn handler(
         ErrorCode::InvalidConfidence
     );
 
-    // Validate stake minimum (0.01 SOL = 10_000_000 lamports)
-    require!(stake >= 10_000_000, ErrorCode::StakeTooLow);
+    // Validate stake minimum (0.01 SOL = 10_000_000 lamports); token stakes
+    // only need to be non-zero since mint decimals vary
+    let min_stake = if call.stake_mint.is_some() { 1 } else { 10_000_000 };
+    require!(stake >= min_stake, ErrorCode::StakeTooLow);
 
     // ============================================
     // TRANSFER STAKE TO ESCROW
     // ============================================
 
-    let transfer = system_program::Transfer {
-        from: ctx.accounts.challenger.to_account_info(),
-        to: ctx.accounts.escrow.to_account_info(),
+    let escrow = Escrow {
+        call_key: call.key(),
+        bump: call.escrow_bump,
+        account: &ctx.accounts.escrow,
+        system_program: &ctx.accounts.system_program,
+        token: StakeToken::for_call(
+            call.stake_mint,
+            ctx.accounts.stake_mint.as_deref(),
+            ctx.accounts.escrow_vault.as_deref(),
+            ctx.accounts.challenger_token_account.as_deref(),
+            ctx.accounts.token_program.as_ref()
+        )?,
     };
 
-    system_program::transfer(
-        CpiContext::new(
-            ctx.accounts.system_program.to_account_info(),
-            transfer
-        ),
-        stake
-    )?;
+    // Net of any Token-2022 transfer fee
+    let stake = escrow.deposit(&ctx.accounts.challenger, stake)?;
+    require!(stake > 0, ErrorCode::StakeTooLow);
 
     // ============================================
     // INITIALIZE CHALLENGE
@@ -116,26 +158,42 @@ pub fn handler(
     challenge.stake = stake;
     challenge.confidence = confidence;
     challenge.created_at = clock.unix_timestamp;
+    challenge.claimed = false;
 
     // ============================================
     // UPDATE CALL
     // ============================================
 
-    // Add challenger to participants array
-    let participant_index = call.challengers_count as usize + 1; // +1 because caller is at index 0
-    require!(
-        participant_index < Call::MAX_PARTICIPANTS,
-        ErrorCode::MaxParticipantsReached
+    // Index the challenger in the call's current participant page
+    let page = &mut ctx.accounts.participant_page;
+    page.ensure_initialized(
+        call.key(),
+        ParticipantPage::page_for(call.challengers_count),
+        ctx.accounts.challenger.key(),
+        ctx.bumps.participant_page
     );
-
-    call.participants[participant_index] = ctx.accounts.challenger.key();
-    call.challengers_count += 1;
+    page.push(ctx.accounts.challenger.key())?;
+
+    call.challengers_count = call.challengers_count
+        .checked_add(1)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    call.total_challenger_stake = call.total_challenger_stake
+        .checked_add(stake)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    call.total_challenger_risk = call.total_challenger_risk
+        .checked_add(payout::at_risk(stake, confidence)?)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    let challenger_profile = &mut ctx.accounts.challenger_profile;
+    challenger_profile.ensure_initialized(ctx.accounts.challenger.key(), ctx.bumps.challenger_profile);
+    challenger_profile.challenges_made = challenger_profile.challenges_made.saturating_add(1);
+    challenger_profile.updated_at = clock.unix_timestamp;
 
     emit!(CallChallenged {
         call_id: call.key(),
         challenger: ctx.accounts.challenger.key(),
-        stake: stake,
-        confidence: confidence,
+        stake,
+        confidence,
     });
 
     msg!("Challenge created for call: {}", call.key());
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+
+/// Withdraw every reward the oracle has accrued
+#[derive(Accounts)]
+pub struct ClaimOracleRewards<'info> {
+    #[account(
+        mut,
+        seeds = [b"oracle_rewards", oracle.key().as_ref()],
+        bump = oracle_rewards.bump
+    )]
+    pub oracle_rewards: Account<'info, OracleRewards>,
+
+    #[account(mut)]
+    pub oracle: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ClaimOracleRewards>) -> Result<()> {
+    let amount = ctx.accounts.oracle_rewards.accrued;
+
+    require!(amount > 0, ErrorCode::NothingToWithdraw);
+
+    ctx.accounts.oracle_rewards.sub_lamports(amount)?;
+    ctx.accounts.oracle.add_lamports(amount)?;
+
+    let oracle_rewards = &mut ctx.accounts.oracle_rewards;
+    oracle_rewards.accrued = 0;
+    oracle_rewards.total_claimed = oracle_rewards.total_claimed
+        .checked_add(amount)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    emit!(OracleRewardsClaimed {
+        oracle: oracle_rewards.oracle,
+        amount,
+    });
+
+    msg!("Oracle claimed {} lamports of rewards", amount);
+
+    Ok(())
+}
+
+#[event]
+pub struct OracleRewardsClaimed {
+    pub oracle: Pubkey,
+    pub amount: u64,
+}
@@ -0,0 +1,179 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::*;
+use crate::escrow::{Escrow, StakeToken};
+use crate::errors::ErrorCode;
+
+/// Pull a settled call's payout for one participant. Permissionless: anyone
+/// may crank it, but funds only ever go to the recorded caller/challenger.
+///
+/// Pass `challenge` to claim for that challenger, or omit it to claim for
+/// the caller.
+#[derive(Accounts)]
+pub struct ClaimPayout<'info> {
+    #[account(
+        mut,
+        constraint = call.status.is_settled() @ ErrorCode::CallNotSettled
+    )]
+    pub call: Account<'info, Call>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", call.key().as_ref()],
+        bump = call.escrow_bump
+    )]
+    pub escrow: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"challenge",
+            call.key().as_ref(),
+            challenge.challenger.as_ref()
+        ],
+        bump,
+        constraint = challenge.call_id == call.key() @ ErrorCode::ChallengeMismatch
+    )]
+    pub challenge: Option<Account<'info, Challenge>>,
+
+    #[account(mut)]
+    pub recipient: SystemAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = UserProfile::SIZE,
+        seeds = [b"profile", recipient.key().as_ref()],
+        bump
+    )]
+    pub recipient_profile: Account<'info, UserProfile>,
+
+    /// Required for token-staked calls; omit for native SOL
+    pub stake_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        mut,
+        associated_token::mint = stake_mint,
+        associated_token::authority = escrow,
+        associated_token::token_program = token_program
+    )]
+    pub escrow_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = stake_mint,
+        associated_token::authority = recipient,
+        associated_token::token_program = token_program
+    )]
+    pub recipient_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+}
+
+pub fn handler(ctx: Context<ClaimPayout>) -> Result<()> {
+    let call = &mut ctx.accounts.call;
+    let call_key = call.key();
+    let clock = Clock::get()?;
+
+    let recipient_key = ctx.accounts.recipient.key();
+    ctx.accounts.recipient_profile.ensure_initialized(recipient_key, ctx.bumps.recipient_profile);
+
+    // ============================================
+    // COMPUTE PAYOUT
+    // ============================================
+
+    let amount = match ctx.accounts.challenge.as_mut() {
+        Some(challenge) => {
+            require!(!challenge.claimed, ErrorCode::AlreadyClaimed);
+            require!(
+                ctx.accounts.recipient.key() == challenge.challenger,
+                ErrorCode::InvalidChallengerWallet
+            );
+
+            challenge.claimed = true;
+            call.claimed_count = call.claimed_count
+                .checked_add(1)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+            let payout = call.challenger_payout(challenge)?;
+
+            // Challenger reputation is settled when their payout is pulled,
+            // so it lags the call's settlement until the claim is made
+            let profile = &mut ctx.accounts.recipient_profile;
+            if call.status.is_refund() {
+                profile.challenges_refunded = profile.challenges_refunded.saturating_add(1);
+                profile.updated_at = clock.unix_timestamp;
+            } else {
+                let won = call.status == CallStatus::ResolvedCallerLoses;
+                let lamports = if call.stake_mint.is_some() {
+                    0 // profile totals are in lamports
+                } else if won {
+                    payout.saturating_sub(challenge.stake)
+                } else {
+                    challenge.stake.saturating_sub(payout)
+                };
+                profile.record_challenge_result(won, challenge.confidence, lamports, clock.unix_timestamp)?;
+            }
+
+            payout
+        }
+        None => {
+            require!(!call.caller_claimed, ErrorCode::AlreadyClaimed);
+            require!(
+                ctx.accounts.recipient.key() == call.caller,
+                ErrorCode::InvalidCallerWallet
+            );
+
+            call.caller_claimed = true;
+
+            call.caller_payout()?
+        }
+    };
+
+    // ============================================
+    // TRANSFER FROM ESCROW
+    // ============================================
+
+    if amount > 0 {
+        let escrow = Escrow {
+            call_key,
+            bump: call.escrow_bump,
+            account: &ctx.accounts.escrow,
+            system_program: &ctx.accounts.system_program,
+            token: StakeToken::for_call(
+                call.stake_mint,
+                ctx.accounts.stake_mint.as_deref(),
+                ctx.accounts.escrow_vault.as_deref(),
+                ctx.accounts.recipient_token_account.as_deref(),
+                ctx.accounts.token_program.as_ref()
+            )?,
+        };
+
+        let to = escrow.payee(&ctx.accounts.recipient.to_account_info());
+        escrow.withdraw(&to, amount)?;
+    }
+
+    emit!(PayoutClaimed {
+        call_id: call_key,
+        recipient: ctx.accounts.recipient.key(),
+        amount,
+    });
+
+    msg!("Claimed {} for {}", amount, ctx.accounts.recipient.key());
+
+    Ok(())
+}
+
+#[event]
+pub struct PayoutClaimed {
+    pub call_id: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+
+/// Close an oracle's attestation once its call is settled, or after the call
+/// itself was closed, returning rent to the oracle. Permissionless.
+#[derive(Accounts)]
+pub struct CloseAttestation<'info> {
+    #[account(
+        mut,
+        seeds = [b"attestation", attestation.call.as_ref(), attestation.oracle.as_ref()],
+        bump = attestation.bump,
+        close = oracle
+    )]
+    pub attestation: Account<'info, Attestation>,
+
+    /// CHECK: The attestation's call; may already be closed (validated in handler)
+    #[account(address = attestation.call @ ErrorCode::AttestationCallMismatch)]
+    pub call: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = attestation.oracle @ ErrorCode::Unauthorized
+    )]
+    pub oracle: SystemAccount<'info>,
+}
+
+pub fn handler(ctx: Context<CloseAttestation>) -> Result<()> {
+    let call_info = &ctx.accounts.call;
+
+    if !call_info.data_is_empty() {
+        require!(call_info.owner == &crate::ID, ErrorCode::AttestationCallMismatch);
+        let call = Call::try_deserialize(&mut &call_info.try_borrow_data()?[..])?;
+        require!(call.status.is_settled(), ErrorCode::CallNotSettled);
+    }
+
+    let attestation = &ctx.accounts.attestation;
+
+    emit!(AttestationClosed {
+        call_id: attestation.call,
+        oracle: attestation.oracle,
+    });
+
+    msg!("Attestation by {} closed for call: {}", attestation.oracle, attestation.call);
+
+    Ok(())
+}
+
+#[event]
+pub struct AttestationClosed {
+    pub call_id: Pubkey,
+    pub oracle: Pubkey,
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+
+/// Close a call's attestation tally once the call is settled, or after the
+/// call itself was closed, returning rent to whoever funded the tally.
+/// Permissionless.
+#[derive(Accounts)]
+pub struct CloseAttestationTally<'info> {
+    #[account(
+        mut,
+        seeds = [b"attestation_tally", tally.call.as_ref()],
+        bump = tally.bump,
+        close = payer
+    )]
+    pub tally: Account<'info, AttestationTally>,
+
+    /// CHECK: The tally's call; may already be closed (validated in handler)
+    #[account(address = tally.call @ ErrorCode::AttestationCallMismatch)]
+    pub call: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = tally.payer @ ErrorCode::Unauthorized
+    )]
+    pub payer: SystemAccount<'info>,
+}
+
+pub fn handler(ctx: Context<CloseAttestationTally>) -> Result<()> {
+    let call_info = &ctx.accounts.call;
+
+    // finalize_from_attestations reads the tally until the call settles
+    if !call_info.data_is_empty() {
+        require!(call_info.owner == &crate::ID, ErrorCode::AttestationCallMismatch);
+        let call = Call::try_deserialize(&mut &call_info.try_borrow_data()?[..])?;
+        require!(call.status.is_settled(), ErrorCode::CallNotSettled);
+    }
+
+    let tally = &ctx.accounts.tally;
+
+    emit!(AttestationTallyClosed {
+        call_id: tally.call,
+        votes: tally.votes.len() as u8,
+    });
+
+    msg!("Attestation tally closed for call: {}", tally.call);
+
+    Ok(())
+}
+
+#[event]
+pub struct AttestationTallyClosed {
+    pub call_id: Pubkey,
+    pub votes: u8,
+}
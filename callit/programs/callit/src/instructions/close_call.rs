@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::*;
+use crate::escrow::{Escrow, StakeToken};
+use crate::errors::ErrorCode;
+
+/// Close a settled call once every payout has been claimed. Call rent, the
+/// escrow's rent reserve and any rounding dust all go back to the caller;
+/// a token-staked call's vault is swept and closed as well. Permissionless.
+#[derive(Accounts)]
+pub struct CloseCall<'info> {
+    #[account(
+        mut,
+        constraint = call.status.is_settled() @ ErrorCode::CallNotSettled,
+        close = caller
+    )]
+    pub call: Account<'info, Call>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", call.key().as_ref()],
+        bump = call.escrow_bump
+    )]
+    pub escrow: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        address = call.caller @ ErrorCode::InvalidCallerWallet
+    )]
+    pub caller: SystemAccount<'info>,
+
+    /// Required for token-staked calls; omit for native SOL. Writable so
+    /// withheld Token-2022 transfer fees can be harvested before closing.
+    #[account(mut)]
+    pub stake_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        mut,
+        associated_token::mint = stake_mint,
+        associated_token::authority = escrow,
+        associated_token::token_program = token_program
+    )]
+    pub escrow_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        token::mint = stake_mint,
+        token::authority = caller,
+        token::token_program = token_program
+    )]
+    pub caller_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+pub fn handler(ctx: Context<CloseCall>) -> Result<()> {
+    let call = &ctx.accounts.call;
+    let call_key = call.key();
+
+    require!(
+        call.caller_claimed && call.claimed_count == call.challengers_count,
+        ErrorCode::ClaimsOutstanding
+    );
+
+    // ============================================
+    // SWEEP ESCROW
+    // ============================================
+
+    // cancel_call already closed a cancelled call's vault
+    let stake_mint = match call.status {
+        CallStatus::Cancelled => None,
+        _ => call.stake_mint,
+    };
+
+    let escrow = Escrow {
+        call_key,
+        bump: call.escrow_bump,
+        account: &ctx.accounts.escrow,
+        system_program: &ctx.accounts.system_program,
+        token: StakeToken::for_call(
+            stake_mint,
+            ctx.accounts.stake_mint.as_deref(),
+            ctx.accounts.escrow_vault.as_deref(),
+            ctx.accounts.caller_token_account.as_deref(),
+            ctx.accounts.token_program.as_ref()
+        )?,
+    };
+
+    let caller = ctx.accounts.caller.to_account_info();
+    let tokens_swept = escrow.close_vault(&caller)?;
+
+    let swept = ctx.accounts.escrow.lamports();
+    if swept > 0 {
+        escrow.withdraw_lamports(&caller, swept)?;
+    }
+
+    emit!(CallClosed {
+        call_id: call_key,
+        caller: call.caller,
+        escrow_swept: swept,
+        tokens_swept,
+    });
+
+    msg!("Call closed, {} lamports swept from escrow", swept);
+
+    Ok(())
+}
+
+#[event]
+pub struct CallClosed {
+    pub call_id: Pubkey,
+    pub caller: Pubkey,
+    pub escrow_swept: u64,
+    pub tokens_swept: u64,
+}
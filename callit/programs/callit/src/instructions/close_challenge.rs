@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+
+/// Close a challenge once its payout has been claimed, returning rent to the
+/// challenger who paid for it. Permissionless.
+#[derive(Accounts)]
+pub struct CloseChallenge<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"challenge",
+            challenge.call_id.as_ref(),
+            challenge.challenger.as_ref()
+        ],
+        bump,
+        constraint = challenge.claimed @ ErrorCode::PayoutNotClaimed,
+        close = challenger
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    #[account(
+        mut,
+        address = challenge.challenger @ ErrorCode::InvalidChallengerWallet
+    )]
+    pub challenger: SystemAccount<'info>,
+}
+
+pub fn handler(ctx: Context<CloseChallenge>) -> Result<()> {
+    let challenge = &ctx.accounts.challenge;
+
+    emit!(ChallengeClosed {
+        call_id: challenge.call_id,
+        challenger: challenge.challenger,
+    });
+
+    msg!("Challenge closed for call: {}", challenge.call_id);
+
+    Ok(())
+}
+
+#[event]
+pub struct ChallengeClosed {
+    pub call_id: Pubkey,
+    pub challenger: Pubkey,
+}
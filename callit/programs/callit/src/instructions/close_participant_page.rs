@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+
+/// Close a participant index page once its call no longer needs it, returning
+/// rent to whoever funded the page. Allowed after every payout of a settled
+/// call has been claimed, or after the call itself was closed. Permissionless.
+#[derive(Accounts)]
+pub struct CloseParticipantPage<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"participants",
+            participant_page.call.as_ref(),
+            &participant_page.page.to_le_bytes()
+        ],
+        bump = participant_page.bump,
+        close = payer
+    )]
+    pub participant_page: Account<'info, ParticipantPage>,
+
+    /// CHECK: The page's call; may already be closed (validated in handler)
+    #[account(address = participant_page.call @ ErrorCode::ParticipantPageMismatch)]
+    pub call: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = participant_page.payer @ ErrorCode::Unauthorized
+    )]
+    pub payer: SystemAccount<'info>,
+}
+
+pub fn handler(ctx: Context<CloseParticipantPage>) -> Result<()> {
+    let call_info = &ctx.accounts.call;
+
+    // Claim cranks may still page through challengers while payouts are outstanding
+    if !call_info.data_is_empty() {
+        require!(call_info.owner == &crate::ID, ErrorCode::ParticipantPageMismatch);
+        let call = Call::try_deserialize(&mut &call_info.try_borrow_data()?[..])?;
+        require!(call.status.is_settled(), ErrorCode::CallNotSettled);
+        require!(
+            call.caller_claimed && call.claimed_count == call.challengers_count,
+            ErrorCode::ClaimsOutstanding
+        );
+    }
+
+    let page = &ctx.accounts.participant_page;
+
+    emit!(ParticipantPageClosed {
+        call_id: page.call,
+        page: page.page,
+    });
+
+    msg!("Participant page {} closed for call: {}", page.page, page.call);
+
+    Ok(())
+}
+
+#[event]
+pub struct ParticipantPageClosed {
+    pub call_id: Pubkey,
+    pub page: u16,
+}
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::*;
+use crate::errors::ErrorCode;
+
+/// Contest a proposed resolution during its dispute period. The disputer
+/// posts a bond, refunded only if the authority's ruling overturns the
+/// proposed outcome.
+#[derive(Accounts)]
+pub struct DisputeResolution<'info> {
+    pub call: Account<'info, Call>,
+
+    #[account(
+        mut,
+        seeds = [b"resolution", call.key().as_ref()],
+        bump = resolution.bump
+    )]
+    pub resolution: Account<'info, Resolution>,
+
+    /// The disputer's challenge; omit when the caller disputes
+    #[account(
+        seeds = [
+            b"challenge",
+            call.key().as_ref(),
+            disputer.key().as_ref()
+        ],
+        bump
+    )]
+    pub challenge: Option<Account<'info, Challenge>>,
+
+    #[account(mut)]
+    pub disputer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<DisputeResolution>) -> Result<()> {
+    let resolution = &mut ctx.accounts.resolution;
+    let disputer = ctx.accounts.disputer.key();
+    let clock = Clock::get()?;
+
+    // ============================================
+    // VALIDATIONS
+    // ============================================
+
+    require!(
+        resolution.status == ResolutionStatus::Proposed,
+        ErrorCode::AlreadyDisputed
+    );
+
+    require!(
+        clock.unix_timestamp < resolution.dispute_deadline,
+        ErrorCode::DisputePeriodEnded
+    );
+
+    require!(
+        disputer == ctx.accounts.call.caller || ctx.accounts.challenge.is_some(),
+        ErrorCode::NotParticipant
+    );
+
+    // ============================================
+    // POST BOND
+    // ============================================
+
+    let transfer = system_program::Transfer {
+        from: ctx.accounts.disputer.to_account_info(),
+        to: resolution.to_account_info(),
+    };
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            transfer
+        ),
+        Resolution::DISPUTE_BOND
+    )?;
+
+    resolution.status = ResolutionStatus::Disputed;
+    resolution.disputer = Some(disputer);
+    resolution.disputed_at = clock.unix_timestamp;
+    resolution.bond = Resolution::DISPUTE_BOND;
+
+    emit!(ResolutionDisputed {
+        call_id: resolution.call,
+        disputer,
+        bond: Resolution::DISPUTE_BOND,
+    });
+
+    msg!("Resolution disputed by {}", disputer);
+
+    Ok(())
+}
+
+#[event]
+pub struct ResolutionDisputed {
+    pub call_id: Pubkey,
+    pub disputer: Pubkey,
+    pub bond: u64,
+}
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::instructions::propose_resolution::ResolutionProposed;
+use crate::errors::ErrorCode;
+
+/// Permissionless: turn a quorum of on-chain attestations into a proposed
+/// resolution. Like `propose_resolution`, this opens the dispute period;
+/// funds move in `finalize_resolution`.
+#[derive(Accounts)]
+pub struct FinalizeFromAttestations<'info> {
+    #[account(
+        mut,
+        constraint = call.status == CallStatus::Active @ ErrorCode::AlreadyResolved
+    )]
+    pub call: Account<'info, Call>,
+
+    #[account(
+        seeds = [b"attestation_tally", call.key().as_ref()],
+        bump = tally.bump
+    )]
+    pub tally: Account<'info, AttestationTally>,
+
+    #[account(
+        init,
+        payer = cranker,
+        space = Resolution::SIZE,
+        seeds = [b"resolution", call.key().as_ref()],
+        bump
+    )]
+    pub resolution: Account<'info, Resolution>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<FinalizeFromAttestations>) -> Result<()> {
+    let call = &mut ctx.accounts.call;
+    let config = &ctx.accounts.config;
+    let tally = &ctx.accounts.tally;
+    let clock = Clock::get()?;
+    let call_key = call.key();
+
+    // Only oracles still in the set count towards the current threshold;
+    // their bonds were checked when they attested
+    let (outcome, observed_price, oracles) = tally
+        .quorum(&config.oracle_signers, config.oracle_threshold)
+        .ok_or(ErrorCode::InsufficientOracleSignatures)?;
+
+    let dispute_deadline = ctx.accounts.resolution.open(
+        call_key,
+        ctx.accounts.cranker.key(),
+        outcome.clone(),
+        observed_price,
+        oracles,
+        clock.unix_timestamp
+    );
+    ctx.accounts.resolution.bump = ctx.bumps.resolution;
+
+    call.status = CallStatus::ResolutionProposed;
+
+    if tally.conflicted {
+        msg!("Oracles disagreed on this call; quorum outcome proposed");
+    }
+
+    emit!(ResolutionProposed {
+        call_id: call_key,
+        outcome,
+        observed_price,
+        dispute_deadline,
+    });
+
+    msg!("Resolution proposed, disputable until {}", dispute_deadline);
+
+    Ok(())
+}
@@ -0,0 +1,270 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::*;
+use crate::escrow::{Escrow, StakeToken};
+use crate::settlement::Settlement;
+use crate::instructions::auto_refund::CallAutoRefunded;
+use crate::errors::ErrorCode;
+
+/// Second phase of oracle resolution. Permissionless once the dispute period
+/// has passed undisputed or the authority has ruled: settles the call with
+/// the final outcome, pays out the dispute bond and credits the oracles'
+/// share of the fee. A dispute left unruled past the ruling period refunds
+/// every participant instead.
+/// Pass the OracleRewards of every oracle in `resolution.oracles` in
+/// `remaining_accounts`. Each bonded oracle has one, opened by `bond_oracle`.
+#[derive(Accounts)]
+pub struct FinalizeResolution<'info> {
+    #[account(
+        mut,
+        constraint = call.status == CallStatus::ResolutionProposed @ ErrorCode::AlreadyResolved
+    )]
+    pub call: Account<'info, Call>,
+
+    #[account(
+        mut,
+        seeds = [b"resolution", call.key().as_ref()],
+        bump = resolution.bump,
+        close = proposer
+    )]
+    pub resolution: Account<'info, Resolution>,
+
+    #[account(
+        mut,
+        address = resolution.proposer @ ErrorCode::Unauthorized
+    )]
+    pub proposer: SystemAccount<'info>,
+
+    /// Required when the resolution was disputed
+    #[account(
+        mut,
+        address = resolution.disputer.unwrap_or_default() @ ErrorCode::InvalidDisputerWallet
+    )]
+    pub disputer: Option<SystemAccount<'info>>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", call.key().as_ref()],
+        bump = call.escrow_bump
+    )]
+    pub escrow: SystemAccount<'info>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury", config.key().as_ref()],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        init_if_needed,
+        payer = cranker,
+        space = UserProfile::SIZE,
+        seeds = [b"profile", call.caller.as_ref()],
+        bump
+    )]
+    pub caller_profile: Account<'info, UserProfile>,
+
+    /// Required for token-staked calls; omit for native SOL
+    pub stake_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        mut,
+        associated_token::mint = stake_mint,
+        associated_token::authority = escrow,
+        associated_token::token_program = token_program
+    )]
+    pub escrow_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        init_if_needed,
+        payer = cranker,
+        associated_token::mint = stake_mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program
+    )]
+    pub treasury_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// CHECK: Anyone can trigger (no authorization required)
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, FinalizeResolution<'info>>) -> Result<()> {
+    let resolution = &ctx.accounts.resolution;
+    let call_key = ctx.accounts.call.key();
+    let clock = Clock::get()?;
+
+    // ============================================
+    // VALIDATIONS
+    // ============================================
+
+    let ruling_expired = match resolution.status {
+        ResolutionStatus::Proposed => {
+            require!(
+                clock.unix_timestamp >= resolution.dispute_deadline,
+                ErrorCode::DisputePeriodOpen
+            );
+            false
+        }
+        ResolutionStatus::Disputed => {
+            require!(
+                clock.unix_timestamp >= resolution.disputed_at + Resolution::RULING_PERIOD,
+                ErrorCode::DisputeAwaitingRuling
+            );
+            true
+        }
+        ResolutionStatus::Ruled => {
+            // Slashing needs the resolution, which is closed below
+            require!(
+                !resolution.dispute_upheld() || resolution.oracles.is_empty(),
+                ErrorCode::OraclesNotSlashed
+            );
+            false
+        }
+    };
+
+    let outcome = resolution.final_outcome();
+    let upheld = resolution.dispute_upheld();
+    let bond = resolution.bond;
+
+    // An overturned outcome's observed price is no longer meaningful
+    let observed_price = if upheld { None } else { resolution.observed_price };
+
+    // ============================================
+    // RELEASE DISPUTE BOND
+    // ============================================
+
+    if bond > 0 {
+        // Forfeited only when the ruling confirmed the proposed outcome
+        let forfeited = resolution.status == ResolutionStatus::Ruled && !upheld;
+        let resolution_info = ctx.accounts.resolution.to_account_info();
+        resolution_info.sub_lamports(bond)?;
+
+        if forfeited {
+            ctx.accounts.treasury.add_lamports(bond)?;
+            let treasury = &mut ctx.accounts.treasury;
+            treasury.total_collected = treasury.total_collected
+                .checked_add(bond)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+        } else {
+            ctx.accounts.disputer
+                .as_ref()
+                .ok_or(ErrorCode::InvalidDisputerWallet)?
+                .add_lamports(bond)?;
+        }
+
+        emit!(DisputeBondReleased {
+            call_id: call_key,
+            amount: bond,
+            forfeited,
+        });
+    }
+
+    // ============================================
+    // SETTLE CALL
+    // ============================================
+
+    if ruling_expired {
+        let call = &mut ctx.accounts.call;
+        call.status = CallStatus::AutoRefunded;
+        call.resolved_at = clock.unix_timestamp;
+        call.protocol_fee = 0;
+
+        let caller_profile = &mut ctx.accounts.caller_profile;
+        caller_profile.ensure_initialized(call.caller, ctx.bumps.caller_profile);
+        caller_profile.calls_refunded = caller_profile.calls_refunded.saturating_add(1);
+        caller_profile.updated_at = clock.unix_timestamp;
+
+        emit!(CallAutoRefunded {
+            call_id: call_key,
+            refunded_at: clock.unix_timestamp,
+        });
+
+        msg!("Dispute was not ruled in time; call refunded");
+
+        return Ok(());
+    }
+
+    let call = &ctx.accounts.call;
+    let escrow = Escrow {
+        call_key,
+        bump: call.escrow_bump,
+        account: &ctx.accounts.escrow,
+        system_program: &ctx.accounts.system_program,
+        token: StakeToken::for_call(
+            call.stake_mint,
+            ctx.accounts.stake_mint.as_deref(),
+            ctx.accounts.escrow_vault.as_deref(),
+            ctx.accounts.treasury_token_account.as_deref(),
+            ctx.accounts.token_program.as_ref()
+        )?,
+    };
+
+    Settlement {
+        call: &mut ctx.accounts.call,
+        escrow,
+        treasury: &mut ctx.accounts.treasury,
+        caller_profile: &mut ctx.accounts.caller_profile,
+        caller_profile_bump: ctx.bumps.caller_profile,
+    }
+    .settle(outcome, observed_price, clock.unix_timestamp)?;
+
+    // ============================================
+    // CREDIT ORACLE REWARDS
+    // ============================================
+
+    // Only native fees are shared; the treasury's counters track lamports
+    let call = &ctx.accounts.call;
+    if call.stake_mint.is_some() {
+        return Ok(());
+    }
+
+    let oracles = ctx.accounts.resolution.oracles.clone();
+    // An unchallenged call's fee is a forfeited stake, not a resolution fee
+    let reward = if call.challengers_count > 0 { call.oracle_reward()? } else { 0 };
+
+    let credited = credit_oracle_rewards(
+        &ctx.accounts.treasury.to_account_info(),
+        ctx.remaining_accounts,
+        &oracles,
+        reward
+    )?;
+
+    if credited > 0 {
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.total_oracle_rewards = treasury.total_oracle_rewards
+            .checked_add(credited)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        emit!(OracleRewardsCredited {
+            call_id: call_key,
+            oracles,
+            total: credited,
+        });
+    }
+
+    Ok(())
+}
+
+#[event]
+pub struct OracleRewardsCredited {
+    pub call_id: Pubkey,
+    pub oracles: Vec<Pubkey>,
+    pub total: u64,
+}
+
+#[event]
+pub struct DisputeBondReleased {
+    pub call_id: Pubkey,
+    pub amount: u64,
+    pub forfeited: bool,
+}
@@ -12,6 +12,15 @@ pub struct Initialize<'info> {
     )]
     pub config: Account<'info, GlobalConfig>,
 
+    #[account(
+        init,
+        payer = authority,
+        space = Treasury::SIZE,
+        seeds = [b"treasury", config.key().as_ref()],
+        bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
     #[account(mut)]
     pub authority: Signer<'info>,
 
@@ -20,17 +29,42 @@ pub struct Initialize<'info> {
 
 pub fn handler(
     ctx: Context<Initialize>,
-    oracle_signers: [Pubkey; 3],
+    oracle_signers: Vec<Pubkey>,
+    oracle_threshold: u8,
+    cluster: Cluster,
 ) -> Result<()> {
     let config = &mut ctx.accounts.config;
 
+    GlobalConfig::validate_oracle_set(&oracle_signers, oracle_threshold)?;
+
+    let oracle_count = oracle_signers.len();
+
     config.authority = ctx.accounts.authority.key();
     config.oracle_signers = oracle_signers;
+    config.oracle_threshold = oracle_threshold;
     config.protocol_fee_bps = 500; // 5%
     config.is_paused = false;
     config.bump = ctx.bumps.config;
+    config.pending_authority = None;
+    config.pending_oracle_set = None;
+    config.cluster = cluster;
+    config.unchallenged_loss_policy = UnchallengedLossPolicy::RefundCaller;
+    config.min_oracle_bond = GlobalConfig::DEFAULT_MIN_ORACLE_BOND;
+    config.oracle_reward_bps = GlobalConfig::DEFAULT_ORACLE_REWARD_BPS;
+
+    let treasury = &mut ctx.accounts.treasury;
+
+    treasury.config = config.key();
+    treasury.total_collected = 0;
+    treasury.total_withdrawn = 0;
+    treasury.bump = ctx.bumps.treasury;
+    treasury.total_oracle_rewards = 0;
 
-    msg!("Protocol initialized with 3 oracle signers");
+    msg!(
+        "Protocol initialized with {}-of-{} oracle signers",
+        oracle_threshold,
+        oracle_count
+    );
 
     Ok(())
 }
@@ -1,15 +1,18 @@
 use anchor_lang::prelude::*;
-use anchor_lang::system_program;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use crate::state::*;
+use crate::escrow::{self, Escrow, StakeToken};
+use crate::pyth::{self, PriceFeedMessage, PriceUpdateV2};
 use crate::errors::ErrorCode;
 
 #[derive(Accounts)]
-#[instruction(claim: String, category: CallCategory, token_address: Option<Pubkey>, target_price: Option<i64>, stake: u64, confidence: u8, deadline: i64, call_nonce: i64)]
+#[instruction(claim: Claim, category: CallCategory, token_address: Option<Pubkey>, price_condition: Option<PriceCondition>, stake: u64, confidence: u8, deadline: i64, call_nonce: i64)]
 pub struct MakeCall<'info> {
     #[account(
         init,
         payer = caller,
-        space = Call::SIZE,
+        space = Call::space(&claim),
         seeds = [b"call", caller.key().as_ref(), &call_nonce.to_le_bytes()],
         bump
     )]
@@ -25,21 +28,53 @@ pub struct MakeCall<'info> {
     #[account(seeds = [b"config"], bump = config.bump)]
     pub config: Account<'info, GlobalConfig>,
 
-    /// CHECK: Pyth price feed (validated in handler)
+    #[account(
+        init_if_needed,
+        payer = caller,
+        space = UserProfile::SIZE,
+        seeds = [b"profile", caller.key().as_ref()],
+        bump
+    )]
+    pub caller_profile: Account<'info, UserProfile>,
+
+    /// CHECK: Pyth PriceUpdateV2 account (owner/discriminator validated in handler)
     pub pyth_price_feed: Option<AccountInfo<'info>>,
 
+    /// SPL or Token-2022 mint to stake in; omit to stake native SOL
+    pub stake_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        init,
+        payer = caller,
+        associated_token::mint = stake_mint,
+        associated_token::authority = escrow,
+        associated_token::token_program = token_program
+    )]
+    pub escrow_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        token::mint = stake_mint,
+        token::authority = caller,
+        token::token_program = token_program
+    )]
+    pub caller_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
     #[account(mut)]
     pub caller: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn handler(
     ctx: Context<MakeCall>,
-    claim: String,
+    claim: Claim,
     category: CallCategory,
     token_address: Option<Pubkey>,
-    target_price: Option<i64>,
+    price_condition: Option<PriceCondition>,
     stake: u64,
     confidence: u8,
     deadline: i64,
@@ -55,11 +90,8 @@ pub fn handler(
 
     require!(!config.is_paused, ErrorCode::ProtocolPaused);
 
-    // Validate claim length
-    require!(
-        claim.len() >= 1 && claim.len() <= Call::MAX_CLAIM_LENGTH,
-        ErrorCode::InvalidClaimLength
-    );
+    // Validate claim title, spec URI and hash
+    claim.validate()?;
 
     // Validate nonce (must be within ±30s of current time)
     require!(
@@ -67,8 +99,16 @@ pub fn handler(
         ErrorCode::InvalidCallNonce
     );
 
-    // Validate stake minimum (0.05 SOL = 50_000_000 lamports)
-    require!(stake >= 50_000_000, ErrorCode::StakeTooLow);
+    // Validate stake minimum (0.05 SOL = 50_000_000 lamports); token stakes
+    // only need to be non-zero since mint decimals vary
+    let stake_mint = ctx.accounts.stake_mint.as_ref().map(|mint| mint.key());
+    match ctx.accounts.stake_mint.as_deref() {
+        Some(mint) => {
+            require!(stake > 0, ErrorCode::StakeTooLow);
+            escrow::validate_stake_mint(mint)?;
+        }
+        None => require!(stake >= 50_000_000, ErrorCode::StakeTooLow),
+    }
 
     // Validate confidence levels
     let valid_confidences = [60, 65, 70, 75, 80, 85, 90, 95];
@@ -84,15 +124,69 @@ pub fn handler(
     );
 
     // Category-specific validations
-    let creation_price = match category {
+    require!(
+        price_condition.is_none() || category == CallCategory::TokenPrice,
+        ErrorCode::InvalidCategoryParams
+    );
+
+    let creation_price = match &category {
         CallCategory::TokenPrice => {
+            let condition = price_condition.as_ref().ok_or(ErrorCode::MissingPriceData)?;
+            let current = read_current_price(
+                ctx.accounts.pyth_price_feed.as_ref(),
+                token_address,
+                clock.unix_timestamp
+            )?;
+
             require!(
-                token_address.is_some() && target_price.is_some(),
-                ErrorCode::MissingPriceData
+                condition.price > 0 && condition.expo == current.exponent,
+                ErrorCode::InvalidPriceCondition
             );
 
-            // TODO: Fetch current price from Pyth
-            // For now, set to None (will be implemented with Pyth integration)
+            require!(
+                !condition.is_met(current.price),
+                ErrorCode::PriceConditionAlreadyMet
+            );
+
+            // Target must be at least 10% away from the current price
+            let distance = (condition.price as i128 - current.price as i128).unsigned_abs();
+            require!(
+                distance * 10 >= current.price as u128,
+                ErrorCode::TargetTooClose
+            );
+
+            Some(current.price)
+        }
+        CallCategory::PriceRange { lower, upper } => {
+            let current_price = read_current_price(
+                ctx.accounts.pyth_price_feed.as_ref(),
+                token_address,
+                clock.unix_timestamp
+            )?
+            .price;
+
+            // A range the price is already outside of is decided at creation
+            require!(
+                0 < *lower && lower < upper && (*lower..=*upper).contains(&current_price),
+                ErrorCode::InvalidCategoryParams
+            );
+
+            Some(current_price)
+        }
+        CallCategory::PercentMove { move_bps } => {
+            // Same 10% minimum as a TokenPrice target
+            require!(*move_bps >= 1_000, ErrorCode::TargetTooClose);
+
+            Some(read_current_price(
+                ctx.accounts.pyth_price_feed.as_ref(),
+                token_address,
+                clock.unix_timestamp
+            )?
+            .price)
+        }
+        CallCategory::MarketCap { threshold } => {
+            require!(token_address.is_some(), ErrorCode::MissingTokenAddress);
+            require!(*threshold > 0, ErrorCode::InvalidCategoryParams);
             None
         }
         CallCategory::RugPrediction => {
@@ -105,18 +199,30 @@ pub fn handler(
     // TRANSFER STAKE TO ESCROW
     // ============================================
 
-    let transfer = system_program::Transfer {
-        from: ctx.accounts.caller.to_account_info(),
-        to: ctx.accounts.escrow.to_account_info(),
+    let escrow = Escrow {
+        call_key: call.key(),
+        bump: ctx.bumps.escrow,
+        account: &ctx.accounts.escrow,
+        system_program: &ctx.accounts.system_program,
+        token: StakeToken::for_call(
+            stake_mint,
+            ctx.accounts.stake_mint.as_deref(),
+            ctx.accounts.escrow_vault.as_deref(),
+            ctx.accounts.caller_token_account.as_deref(),
+            ctx.accounts.token_program.as_ref()
+        )?,
     };
 
-    system_program::transfer(
-        CpiContext::new(
-            ctx.accounts.system_program.to_account_info(),
-            transfer
-        ),
-        stake
-    )?;
+    // A Token-2022 transfer fee may leave the vault with less than `stake`;
+    // the call is only worth what actually arrived
+    let stake = escrow.deposit(&ctx.accounts.caller, stake)?;
+    require!(stake > 0, ErrorCode::StakeTooLow);
+
+    // Native escrow also holds a rent-exempt reserve so rounding dust left
+    // after claims never drops it into a rent-paying state
+    if stake_mint.is_none() {
+        escrow.deposit_lamports(&ctx.accounts.caller, Rent::get()?.minimum_balance(0))?;
+    }
 
     // ============================================
     // INITIALIZE CALL
@@ -126,7 +232,7 @@ pub fn handler(
     call.claim = claim;
     call.category = category;
     call.token_address = token_address;
-    call.target_price = target_price;
+    call.price_condition = price_condition;
     call.creation_price = creation_price;
     call.stake = stake;
     call.confidence = confidence;
@@ -134,16 +240,31 @@ pub fn handler(
     call.created_at = clock.unix_timestamp;
     call.status = CallStatus::Active;
     call.challengers_count = 0;
-    call.participants = [Pubkey::default(); 20];
-    call.participants[0] = ctx.accounts.caller.key(); // Caller is first participant
     call.escrow_bump = ctx.bumps.escrow;
+    call.total_challenger_stake = 0;
+    call.total_challenger_risk = 0;
+    call.protocol_fee = 0;
+    call.resolved_at = 0;
+    call.claimed_count = 0;
+    call.caller_claimed = false;
+    call.stake_mint = stake_mint;
+    call.unchallenged_loss_policy = config.unchallenged_loss_policy.clone();
+    call.protocol_fee_bps = config.protocol_fee_bps;
+    call.oracle_reward_bps = config.oracle_reward_bps;
+
+    let caller_profile = &mut ctx.accounts.caller_profile;
+    caller_profile.ensure_initialized(ctx.accounts.caller.key(), ctx.bumps.caller_profile);
+    caller_profile.calls_made = caller_profile.calls_made.saturating_add(1);
+    caller_profile.updated_at = clock.unix_timestamp;
 
     emit!(CallCreated {
         call_id: call.key(),
         caller: call.caller,
-        stake: stake,
-        confidence: confidence,
-        deadline: deadline,
+        claim_hash: call.claim.hash,
+        stake_mint,
+        stake,
+        confidence,
+        deadline,
     });
 
     msg!("Call created: {}", call.key());
@@ -151,10 +272,28 @@ pub fn handler(
     Ok(())
 }
 
+/// Current Pyth price for price-based categories. `token_address` holds the
+/// Pyth feed ID; prices use the feed's exponent.
+fn read_current_price(
+    price_feed: Option<&AccountInfo>,
+    token_address: Option<Pubkey>,
+    now: i64,
+) -> Result<PriceFeedMessage> {
+    let feed_id = token_address.ok_or(ErrorCode::MissingPriceData)?;
+    let price_feed = price_feed.ok_or(ErrorCode::InvalidPythAccount)?;
+
+    let price_update = PriceUpdateV2::load(price_feed)?;
+    Ok(price_update
+        .get_price_no_older_than(now, pyth::MAX_PRICE_AGE, &feed_id.to_bytes())?
+        .clone())
+}
+
 #[event]
 pub struct CallCreated {
     pub call_id: Pubkey,
     pub caller: Pubkey,
+    pub claim_hash: [u8; 32],
+    pub stake_mint: Option<Pubkey>,
     pub stake: u64,
     pub confidence: u8,
     pub deadline: i64,
@@ -0,0 +1,125 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::*;
+use crate::errors::ErrorCode;
+
+/// One-off upgrade of a v1 config (fixed 2-of-3 oracle set) to the current
+/// layout. Also creates the treasury PDA, which v1 deployments never had.
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    /// CHECK: Legacy config account, owner and layout validated in handler
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump,
+        owner = crate::ID
+    )]
+    pub config: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Treasury::SIZE,
+        seeds = [b"treasury", config.key().as_ref()],
+        bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateConfig>, cluster: Cluster) -> Result<()> {
+    let config_info = ctx.accounts.config.to_account_info();
+
+    // ============================================
+    // READ LEGACY LAYOUT
+    // ============================================
+
+    let legacy = {
+        let data = config_info.try_borrow_data()?;
+
+        require!(
+            data.len() == GlobalConfigV1::SIZE,
+            ErrorCode::ConfigAlreadyMigrated
+        );
+        require!(
+            &data[..8] == GlobalConfig::DISCRIMINATOR,
+            anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch
+        );
+
+        GlobalConfigV1::deserialize(&mut &data[8..])?
+    };
+
+    require!(
+        legacy.authority == ctx.accounts.authority.key(),
+        ErrorCode::Unauthorized
+    );
+
+    // ============================================
+    // REALLOC (authority tops up rent)
+    // ============================================
+
+    let required_lamports = Rent::get()?.minimum_balance(GlobalConfig::SIZE);
+    let shortfall = required_lamports.saturating_sub(config_info.lamports());
+
+    if shortfall > 0 {
+        let transfer = system_program::Transfer {
+            from: ctx.accounts.authority.to_account_info(),
+            to: config_info.clone(),
+        };
+
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                transfer
+            ),
+            shortfall
+        )?;
+    }
+
+    config_info.resize(GlobalConfig::SIZE)?;
+
+    // ============================================
+    // WRITE CURRENT LAYOUT
+    // ============================================
+
+    let config = GlobalConfig {
+        authority: legacy.authority,
+        oracle_signers: legacy.oracle_signers.to_vec(),
+        oracle_threshold: GlobalConfigV1::ORACLE_THRESHOLD,
+        protocol_fee_bps: legacy.protocol_fee_bps,
+        is_paused: legacy.is_paused,
+        bump: legacy.bump,
+        pending_authority: None,
+        pending_oracle_set: None,
+        cluster,
+        unchallenged_loss_policy: UnchallengedLossPolicy::RefundCaller,
+        min_oracle_bond: GlobalConfig::DEFAULT_MIN_ORACLE_BOND,
+        oracle_reward_bps: GlobalConfig::DEFAULT_ORACLE_REWARD_BPS,
+    };
+
+    {
+        let mut data = config_info.try_borrow_mut_data()?;
+        let mut writer: &mut [u8] = &mut data[..];
+        config.try_serialize(&mut writer)?;
+    }
+
+    let treasury = &mut ctx.accounts.treasury;
+
+    treasury.config = config_info.key();
+    treasury.total_collected = 0;
+    treasury.total_withdrawn = 0;
+    treasury.bump = ctx.bumps.treasury;
+    treasury.total_oracle_rewards = 0;
+
+    msg!(
+        "Config migrated to {}-of-{} oracle quorum",
+        config.oracle_threshold,
+        config.oracle_signers.len()
+    );
+
+    Ok(())
+}
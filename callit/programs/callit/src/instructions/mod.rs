@@ -1,11 +1,71 @@
+#![allow(ambiguous_glob_reexports)]
+
 pub mod initialize;
 pub mod make_call;
 pub mod challenge_call;
-pub mod resolve_call;
+pub mod propose_resolution;
+pub mod submit_attestation;
+pub mod finalize_from_attestations;
+pub mod bond_oracle;
+pub mod unbond_oracle;
+pub mod withdraw_oracle_bond;
+pub mod slash_oracle;
+pub mod claim_oracle_rewards;
+pub mod dispute_resolution;
+pub mod rule_dispute;
+pub mod finalize_resolution;
+pub mod resolve_with_price_feed;
 pub mod auto_refund;
+pub mod cancel_call;
+pub mod claim_payout;
+pub mod close_challenge;
+pub mod close_call;
+pub mod close_participant_page;
+pub mod close_attestation;
+pub mod close_attestation_tally;
+pub mod withdraw_treasury;
+pub mod set_paused;
+pub mod set_protocol_fee;
+pub mod set_unchallenged_loss_policy;
+pub mod set_min_oracle_bond;
+pub mod set_oracle_reward_share;
+pub mod propose_authority;
+pub mod accept_authority;
+pub mod propose_oracle_set;
+pub mod apply_oracle_set;
+pub mod migrate_config;
 
 pub use initialize::*;
 pub use make_call::*;
 pub use challenge_call::*;
-pub use resolve_call::*;
+pub use propose_resolution::*;
+pub use submit_attestation::*;
+pub use finalize_from_attestations::*;
+pub use bond_oracle::*;
+pub use unbond_oracle::*;
+pub use withdraw_oracle_bond::*;
+pub use slash_oracle::*;
+pub use claim_oracle_rewards::*;
+pub use dispute_resolution::*;
+pub use rule_dispute::*;
+pub use finalize_resolution::*;
+pub use resolve_with_price_feed::*;
 pub use auto_refund::*;
+pub use cancel_call::*;
+pub use claim_payout::*;
+pub use close_challenge::*;
+pub use close_call::*;
+pub use close_participant_page::*;
+pub use close_attestation::*;
+pub use close_attestation_tally::*;
+pub use withdraw_treasury::*;
+pub use set_paused::*;
+pub use set_protocol_fee::*;
+pub use set_unchallenged_loss_policy::*;
+pub use set_min_oracle_bond::*;
+pub use set_oracle_reward_share::*;
+pub use propose_authority::*;
+pub use accept_authority::*;
+pub use propose_oracle_set::*;
+pub use apply_oracle_set::*;
+pub use migrate_config::*;
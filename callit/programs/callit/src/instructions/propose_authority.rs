@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+
+/// Step 1 of the authority handover: current authority nominates a successor
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ProposeAuthority>, new_authority: Option<Pubkey>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    // Passing None cancels a pending handover
+    config.pending_authority = new_authority;
+
+    emit!(AuthorityProposed {
+        authority: config.authority,
+        pending_authority: new_authority,
+    });
+
+    msg!("Pending authority set to {:?}", new_authority);
+
+    Ok(())
+}
+
+#[event]
+pub struct AuthorityProposed {
+    pub authority: Pubkey,
+    pub pending_authority: Option<Pubkey>,
+}
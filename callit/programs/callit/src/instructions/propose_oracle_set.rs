@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+
+/// Queue a new oracle set; it can only be applied after the rotation delay
+#[derive(Accounts)]
+pub struct ProposeOracleSet<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<ProposeOracleSet>,
+    oracle_signers: Vec<Pubkey>,
+    oracle_threshold: u8,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let clock = Clock::get()?;
+
+    GlobalConfig::validate_oracle_set(&oracle_signers, oracle_threshold)?;
+
+    // Re-proposing replaces the pending set and restarts the timelock
+    let eta = clock.unix_timestamp
+        .checked_add(GlobalConfig::ORACLE_ROTATION_DELAY)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    config.pending_oracle_set = Some(PendingOracleSet {
+        oracle_signers: oracle_signers.clone(),
+        oracle_threshold,
+        eta,
+    });
+
+    emit!(OracleSetProposed {
+        authority: config.authority,
+        oracle_signers,
+        oracle_threshold,
+        eta,
+    });
+
+    msg!("Oracle set rotation queued, applicable at {}", eta);
+
+    Ok(())
+}
+
+#[event]
+pub struct OracleSetProposed {
+    pub authority: Pubkey,
+    pub oracle_signers: Vec<Pubkey>,
+    pub oracle_threshold: u8,
+    pub eta: i64,
+}
@@ -0,0 +1,164 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as sysvar_ix;
+use crate::state::*;
+use crate::attestation::{Outcome, ResolutionAttestation};
+use crate::ed25519;
+use crate::errors::ErrorCode;
+
+/// First phase of oracle resolution: record the quorum-signed outcome and
+/// open the dispute period. Nothing moves until `finalize_resolution`.
+/// Pass each signing oracle's `OracleBond` in `remaining_accounts`; only
+/// sufficiently bonded oracles count towards the quorum.
+#[derive(Accounts)]
+pub struct ProposeResolution<'info> {
+    #[account(
+        mut,
+        constraint = call.status == CallStatus::Active @ ErrorCode::AlreadyResolved
+    )]
+    pub call: Account<'info, Call>,
+
+    #[account(
+        init,
+        payer = oracle,
+        space = Resolution::SIZE,
+        seeds = [b"resolution", call.key().as_ref()],
+        bump
+    )]
+    pub resolution: Account<'info, Resolution>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    /// CHECK: Instructions sysvar, read for the Ed25519 precompile instructions
+    #[account(address = sysvar_ix::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    /// Submits the proposal and pays for the Resolution account. Need not be
+    /// an oracle: the oracles' signatures are checked per attestation.
+    #[account(mut)]
+    pub oracle: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<ProposeResolution>,
+    attestation: ResolutionAttestation,
+) -> Result<()> {
+    let call = &mut ctx.accounts.call;
+    let config = &ctx.accounts.config;
+    let clock = Clock::get()?;
+    let call_key = call.key();
+
+    // ============================================
+    // VALIDATIONS
+    // ============================================
+
+    require!(
+        clock.unix_timestamp >= call.deadline,
+        ErrorCode::DeadlineNotReached
+    );
+
+    // Objective price calls settle trustlessly via resolve_with_price_feed
+    require!(
+        !call.is_price_feed_resolvable(),
+        ErrorCode::UsePriceFeedResolution
+    );
+
+    attestation.validate(
+        call_key,
+        call,
+        &config.cluster,
+        clock.unix_timestamp
+    )?;
+
+    // Collect the authorized oracles whose Ed25519 signatures over the
+    // attestation message precede this instruction
+    let signers = verify_oracle_signatures(
+        &ctx.accounts.instructions_sysvar,
+        &config.oracle_signers,
+        &attestation.message()?
+    )?;
+
+    let oracles = bonded_oracles(ctx.remaining_accounts, &signers, config.min_oracle_bond)?;
+
+    // Require the configured quorum of bonded oracle signatures
+    require!(
+        oracles.len() >= config.oracle_threshold as usize,
+        ErrorCode::InsufficientOracleSignatures
+    );
+
+    msg!("Verified {} bonded oracle signatures", oracles.len());
+
+    // ============================================
+    // RECORD PROPOSAL
+    // ============================================
+
+    let dispute_deadline = ctx.accounts.resolution.open(
+        call_key,
+        ctx.accounts.oracle.key(),
+        attestation.outcome.clone(),
+        attestation.observed_price,
+        oracles,
+        clock.unix_timestamp
+    );
+    ctx.accounts.resolution.bump = ctx.bumps.resolution;
+
+    call.status = CallStatus::ResolutionProposed;
+
+    emit!(ResolutionProposed {
+        call_id: call_key,
+        outcome: attestation.outcome,
+        observed_price: attestation.observed_price,
+        dispute_deadline,
+    });
+
+    msg!("Resolution proposed, disputable until {}", dispute_deadline);
+
+    Ok(())
+}
+
+/// Authorized oracles that signed `expected_message` in the Ed25519 precompile
+/// instructions preceding this one, read with the strict `ed25519` parser
+fn verify_oracle_signatures(
+    instructions_sysvar: &AccountInfo,
+    authorized_oracles: &[Pubkey],
+    expected_message: &[u8],
+) -> Result<Vec<Pubkey>> {
+
+    let mut verified_oracles: Vec<Pubkey> = Vec::new();
+
+    // Load current instruction index
+    let current_index = sysvar_ix::load_current_index_checked(instructions_sysvar)?;
+
+    // Scan all instructions in transaction for Ed25519 SigVerify instructions
+    for i in 0..current_index {
+        let ix = sysvar_ix::load_instruction_at_checked(i as usize, instructions_sysvar)
+            .map_err(|_| ErrorCode::InvalidInstructionSysvar)?;
+
+        // Skip if not Ed25519 program
+        if ix.program_id != ed25519::ED25519_PROGRAM_ID {
+            continue;
+        }
+
+        for signature in ed25519::parse(&ix.data)? {
+            // Count each authorized oracle once, and only for the exact attestation
+            if authorized_oracles.contains(&signature.pubkey)
+                && !verified_oracles.contains(&signature.pubkey)
+                && signature.message == expected_message
+            {
+                verified_oracles.push(signature.pubkey);
+            }
+        }
+    }
+
+    Ok(verified_oracles)
+}
+
+#[event]
+pub struct ResolutionProposed {
+    pub call_id: Pubkey,
+    pub outcome: Outcome,
+    pub observed_price: Option<i64>,
+    pub dispute_deadline: i64,
+}
@@ -0,0 +1,175 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::*;
+use crate::escrow::{Escrow, StakeToken};
+use crate::attestation::Outcome;
+use crate::pyth::{self, PriceUpdateV2};
+use crate::settlement::Settlement;
+use crate::errors::ErrorCode;
+
+/// Permissionless settlement of an AtDeadline TokenPrice or PercentMove call
+/// from the Pyth price published right after the deadline. No oracle signatures involved.
+#[derive(Accounts)]
+pub struct ResolveWithPriceFeed<'info> {
+    #[account(
+        mut,
+        constraint = call.status == CallStatus::Active @ ErrorCode::AlreadyResolved
+    )]
+    pub call: Account<'info, Call>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", call.key().as_ref()],
+        bump = call.escrow_bump
+    )]
+    pub escrow: SystemAccount<'info>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury", config.key().as_ref()],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        init_if_needed,
+        payer = cranker,
+        space = UserProfile::SIZE,
+        seeds = [b"profile", call.caller.as_ref()],
+        bump
+    )]
+    pub caller_profile: Account<'info, UserProfile>,
+
+    /// Required for token-staked calls; omit for native SOL
+    pub stake_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        mut,
+        associated_token::mint = stake_mint,
+        associated_token::authority = escrow,
+        associated_token::token_program = token_program
+    )]
+    pub escrow_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        init_if_needed,
+        payer = cranker,
+        associated_token::mint = stake_mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program
+    )]
+    pub treasury_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// CHECK: Pyth PriceUpdateV2 account (owner/discriminator validated in handler)
+    pub pyth_price_feed: AccountInfo<'info>,
+
+    /// CHECK: Anyone can trigger (no authorization required)
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+}
+
+pub fn handler(ctx: Context<ResolveWithPriceFeed>) -> Result<()> {
+    let call = &ctx.accounts.call;
+    let clock = Clock::get()?;
+
+    // ============================================
+    // VALIDATIONS
+    // ============================================
+
+    require!(
+        clock.unix_timestamp >= call.deadline,
+        ErrorCode::DeadlineNotReached
+    );
+
+    require!(
+        call.is_price_feed_resolvable(),
+        ErrorCode::NotPriceFeedResolvable
+    );
+
+    let (feed_id, creation_price) = match (call.token_address, call.creation_price) {
+        (Some(feed_id), Some(creation)) => (feed_id, creation),
+        _ => return Err(ErrorCode::MissingPriceData.into()),
+    };
+
+    // ============================================
+    // READ SETTLEMENT PRICE
+    // ============================================
+
+    // Only the first update published at or after the deadline counts, so
+    // the cranker cannot cherry-pick a later print
+    let price_update = PriceUpdateV2::load(&ctx.accounts.pyth_price_feed)?;
+    let settlement = price_update.get_first_price_at_or_after(
+        call.deadline,
+        pyth::RESOLUTION_PRICE_WINDOW,
+        &feed_id.to_bytes()
+    )?;
+    let settlement_price = settlement.price;
+
+    let caller_wins = match call.category {
+        CallCategory::TokenPrice => {
+            let condition = call.price_condition.as_ref().ok_or(ErrorCode::MissingPriceData)?;
+            require!(
+                condition.expo == settlement.exponent,
+                ErrorCode::InvalidPriceCondition
+            );
+
+            msg!(
+                "Settlement price {} vs target {} (created at {})",
+                settlement_price,
+                condition.price,
+                creation_price
+            );
+
+            condition.is_met(settlement_price)
+        }
+        CallCategory::PercentMove { move_bps } => {
+            msg!(
+                "Settlement price {} vs creation price {} (needs {} bps move)",
+                settlement_price,
+                creation_price,
+                move_bps
+            );
+
+            let change = (settlement_price as i128 - creation_price as i128).unsigned_abs();
+            change * 10_000 >= creation_price as u128 * move_bps as u128
+        }
+        _ => return Err(ErrorCode::NotPriceFeedResolvable.into()),
+    };
+
+    let outcome = if caller_wins {
+        Outcome::CallerWins
+    } else {
+        Outcome::CallerLoses
+    };
+
+    let escrow = Escrow {
+        call_key: call.key(),
+        bump: call.escrow_bump,
+        account: &ctx.accounts.escrow,
+        system_program: &ctx.accounts.system_program,
+        token: StakeToken::for_call(
+            call.stake_mint,
+            ctx.accounts.stake_mint.as_deref(),
+            ctx.accounts.escrow_vault.as_deref(),
+            ctx.accounts.treasury_token_account.as_deref(),
+            ctx.accounts.token_program.as_ref()
+        )?,
+    };
+
+    Settlement {
+        call: &mut ctx.accounts.call,
+        escrow,
+        treasury: &mut ctx.accounts.treasury,
+        caller_profile: &mut ctx.accounts.caller_profile,
+        caller_profile_bump: ctx.bumps.caller_profile,
+    }
+    .settle(outcome, Some(settlement_price), clock.unix_timestamp)
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::attestation::Outcome;
+use crate::errors::ErrorCode;
+
+/// Escalation path: the protocol authority rules on a disputed resolution.
+/// The ruling takes effect at `finalize_resolution`.
+#[derive(Accounts)]
+pub struct RuleDispute<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"resolution", resolution.call.as_ref()],
+        bump = resolution.bump
+    )]
+    pub resolution: Account<'info, Resolution>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<RuleDispute>, outcome: Outcome) -> Result<()> {
+    let resolution = &mut ctx.accounts.resolution;
+    let clock = Clock::get()?;
+
+    require!(
+        resolution.status == ResolutionStatus::Disputed,
+        ErrorCode::NotDisputed
+    );
+
+    // Past this point an unruled dispute refunds everyone instead
+    require!(
+        clock.unix_timestamp < resolution.disputed_at + Resolution::RULING_PERIOD,
+        ErrorCode::RulingPeriodEnded
+    );
+
+    resolution.ruling = Some(outcome.clone());
+    resolution.status = ResolutionStatus::Ruled;
+
+    emit!(DisputeRuled {
+        call_id: resolution.call,
+        proposed: resolution.outcome.clone(),
+        ruling: outcome,
+    });
+
+    msg!("Dispute ruled for call: {}", resolution.call);
+
+    Ok(())
+}
+
+#[event]
+pub struct DisputeRuled {
+    pub call_id: Pubkey,
+    pub proposed: Outcome,
+    pub ruling: Outcome,
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct SetMinOracleBond<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetMinOracleBond>, min_oracle_bond: u64) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    config.min_oracle_bond = min_oracle_bond;
+
+    emit!(MinOracleBondUpdated {
+        authority: config.authority,
+        min_oracle_bond,
+    });
+
+    msg!("Minimum oracle bond set to {} lamports", min_oracle_bond);
+
+    Ok(())
+}
+
+#[event]
+pub struct MinOracleBondUpdated {
+    pub authority: Pubkey,
+    pub min_oracle_bond: u64,
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct SetOracleRewardShare<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetOracleRewardShare>, oracle_reward_bps: u16) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    require!(oracle_reward_bps <= 10_000, ErrorCode::OracleRewardShareTooHigh);
+
+    let old_reward_bps = config.oracle_reward_bps;
+    config.oracle_reward_bps = oracle_reward_bps;
+
+    emit!(OracleRewardShareUpdated {
+        authority: config.authority,
+        old_reward_bps,
+        new_reward_bps: oracle_reward_bps,
+    });
+
+    msg!("Oracle reward share updated: {} -> {} bps", old_reward_bps, oracle_reward_bps);
+
+    Ok(())
+}
+
+#[event]
+pub struct OracleRewardShareUpdated {
+    pub authority: Pubkey,
+    pub old_reward_bps: u16,
+    pub new_reward_bps: u16,
+}
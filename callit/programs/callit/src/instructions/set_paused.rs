@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetPaused>, is_paused: bool) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    config.is_paused = is_paused;
+
+    emit!(PauseUpdated {
+        authority: config.authority,
+        is_paused,
+    });
+
+    msg!("Protocol paused: {}", is_paused);
+
+    Ok(())
+}
+
+#[event]
+pub struct PauseUpdated {
+    pub authority: Pubkey,
+    pub is_paused: bool,
+}
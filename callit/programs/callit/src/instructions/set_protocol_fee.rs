@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct SetProtocolFee<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetProtocolFee>, protocol_fee_bps: u16) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    require!(
+        protocol_fee_bps <= GlobalConfig::MAX_PROTOCOL_FEE_BPS,
+        ErrorCode::ProtocolFeeTooHigh
+    );
+
+    let old_fee_bps = config.protocol_fee_bps;
+    config.protocol_fee_bps = protocol_fee_bps;
+
+    emit!(ProtocolFeeUpdated {
+        authority: config.authority,
+        old_fee_bps,
+        new_fee_bps: protocol_fee_bps,
+    });
+
+    msg!("Protocol fee updated: {} -> {} bps", old_fee_bps, protocol_fee_bps);
+
+    Ok(())
+}
+
+#[event]
+pub struct ProtocolFeeUpdated {
+    pub authority: Pubkey,
+    pub old_fee_bps: u16,
+    pub new_fee_bps: u16,
+}
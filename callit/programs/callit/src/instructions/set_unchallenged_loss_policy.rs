@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct SetUnchallengedLossPolicy<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetUnchallengedLossPolicy>,
+    policy: UnchallengedLossPolicy,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    config.unchallenged_loss_policy = policy.clone();
+
+    emit!(UnchallengedLossPolicyUpdated {
+        authority: config.authority,
+        policy,
+    });
+
+    msg!("Unchallenged loss policy updated");
+
+    Ok(())
+}
+
+#[event]
+pub struct UnchallengedLossPolicyUpdated {
+    pub authority: Pubkey,
+    pub policy: UnchallengedLossPolicy,
+}
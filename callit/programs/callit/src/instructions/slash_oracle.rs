@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+
+/// Permissionless: once the authority's ruling has overturned a proposed
+/// outcome, slash the whole bond of each oracle that backed it to the
+/// treasury. `finalize_resolution` waits until every such oracle is slashed.
+#[derive(Accounts)]
+pub struct SlashOracle<'info> {
+    #[account(
+        mut,
+        seeds = [b"resolution", resolution.call.as_ref()],
+        bump = resolution.bump
+    )]
+    pub resolution: Account<'info, Resolution>,
+
+    #[account(
+        mut,
+        seeds = [b"oracle_bond", oracle_bond.oracle.as_ref()],
+        bump = oracle_bond.bump
+    )]
+    pub oracle_bond: Account<'info, OracleBond>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury", config.key().as_ref()],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+}
+
+pub fn handler(ctx: Context<SlashOracle>) -> Result<()> {
+    let resolution = &mut ctx.accounts.resolution;
+    let oracle = ctx.accounts.oracle_bond.oracle;
+
+    // ============================================
+    // VALIDATIONS
+    // ============================================
+
+    require!(
+        resolution.status == ResolutionStatus::Ruled && resolution.dispute_upheld(),
+        ErrorCode::NotSlashable
+    );
+
+    let position = resolution.oracles
+        .iter()
+        .position(|o| *o == oracle)
+        .ok_or(ErrorCode::NotSlashable)?;
+
+    // ============================================
+    // SLASH
+    // ============================================
+
+    resolution.oracles.swap_remove(position);
+
+    let amount = ctx.accounts.oracle_bond.slashable();
+
+    if amount > 0 {
+        ctx.accounts.oracle_bond.sub_lamports(amount)?;
+        ctx.accounts.treasury.add_lamports(amount)?;
+
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.total_collected = treasury.total_collected
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+    }
+
+    let oracle_bond = &mut ctx.accounts.oracle_bond;
+    oracle_bond.amount = 0;
+    oracle_bond.unbonding_amount = 0;
+    oracle_bond.slashed_total = oracle_bond.slashed_total.saturating_add(amount);
+
+    emit!(OracleSlashed {
+        call_id: resolution.call,
+        oracle,
+        amount,
+    });
+
+    msg!("Slashed {} lamports from oracle {}", amount, oracle);
+
+    Ok(())
+}
+
+#[event]
+pub struct OracleSlashed {
+    pub call_id: Pubkey,
+    pub oracle: Pubkey,
+    pub amount: u64,
+}
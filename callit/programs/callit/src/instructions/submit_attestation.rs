@@ -0,0 +1,151 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::attestation::Outcome;
+use crate::errors::ErrorCode;
+
+/// Asynchronous alternative to `propose_resolution`: each oracle records its
+/// own verdict on-chain, without gathering peer signatures. Once a quorum
+/// agrees, anyone can call `finalize_from_attestations`.
+#[derive(Accounts)]
+pub struct SubmitAttestation<'info> {
+    #[account(
+        constraint = call.status == CallStatus::Active @ ErrorCode::AlreadyResolved
+    )]
+    pub call: Account<'info, Call>,
+
+    #[account(
+        init,
+        payer = oracle,
+        space = Attestation::SIZE,
+        seeds = [b"attestation", call.key().as_ref(), oracle.key().as_ref()],
+        bump
+    )]
+    pub attestation: Account<'info, Attestation>,
+
+    #[account(
+        init_if_needed,
+        payer = oracle,
+        space = AttestationTally::SIZE,
+        seeds = [b"attestation_tally", call.key().as_ref()],
+        bump
+    )]
+    pub tally: Account<'info, AttestationTally>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(
+        seeds = [b"oracle_bond", oracle.key().as_ref()],
+        bump = oracle_bond.bump
+    )]
+    pub oracle_bond: Account<'info, OracleBond>,
+
+    #[account(mut)]
+    pub oracle: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<SubmitAttestation>,
+    outcome: Outcome,
+    observed_price: Option<i64>,
+    evidence_hash: [u8; 32],
+    claim_hash: [u8; 32],
+) -> Result<()> {
+    let call = &ctx.accounts.call;
+    let config = &ctx.accounts.config;
+    let oracle = ctx.accounts.oracle.key();
+    let clock = Clock::get()?;
+
+    // ============================================
+    // VALIDATIONS
+    // ============================================
+
+    require!(
+        config.oracle_signers.contains(&oracle),
+        ErrorCode::UnauthorizedOracle
+    );
+
+    require!(
+        ctx.accounts.oracle_bond.amount >= config.min_oracle_bond,
+        ErrorCode::InsufficientOracleBond
+    );
+
+    require!(
+        clock.unix_timestamp >= call.deadline,
+        ErrorCode::DeadlineNotReached
+    );
+
+    // Objective price calls settle trustlessly via resolve_with_price_feed
+    require!(
+        !call.is_price_feed_resolvable(),
+        ErrorCode::UsePriceFeedResolution
+    );
+
+    // The oracle must have judged the exact claim spec the call points to
+    require!(
+        claim_hash == call.claim.hash,
+        ErrorCode::AttestationClaimMismatch
+    );
+
+    // ============================================
+    // RECORD ATTESTATION
+    // ============================================
+
+    let attestation = &mut ctx.accounts.attestation;
+    attestation.call = call.key();
+    attestation.oracle = oracle;
+    attestation.outcome = outcome.clone();
+    attestation.observed_price = observed_price;
+    attestation.evidence_hash = evidence_hash;
+    attestation.submitted_at = clock.unix_timestamp;
+    attestation.bump = ctx.bumps.attestation;
+
+    let tally = &mut ctx.accounts.tally;
+    tally.ensure_initialized(call.key(), oracle, ctx.bumps.tally);
+
+    let vote = OracleVote {
+        oracle,
+        outcome: outcome.clone(),
+        observed_price,
+    };
+
+    if let Some(conflicting_outcome) = tally.record(vote, &config.oracle_signers) {
+        emit!(AttestationConflict {
+            call_id: call.key(),
+            oracle,
+            outcome: outcome.clone(),
+            conflicting_outcome,
+        });
+
+        msg!("Attestation conflicts with an earlier oracle's outcome");
+    }
+
+    emit!(AttestationSubmitted {
+        call_id: call.key(),
+        oracle,
+        outcome,
+        observed_price,
+        evidence_hash,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct AttestationSubmitted {
+    pub call_id: Pubkey,
+    pub oracle: Pubkey,
+    pub outcome: Outcome,
+    pub observed_price: Option<i64>,
+    pub evidence_hash: [u8; 32],
+}
+
+#[event]
+pub struct AttestationConflict {
+    pub call_id: Pubkey,
+    pub oracle: Pubkey,
+    pub outcome: Outcome,
+    pub conflicting_outcome: Outcome,
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+
+/// Start unbonding `amount`. It stops counting towards the minimum at once
+/// but stays slashable until `withdraw_oracle_bond` after the cooldown.
+/// Unbonding more restarts the cooldown for the whole unbonding amount.
+#[derive(Accounts)]
+pub struct UnbondOracle<'info> {
+    #[account(
+        mut,
+        seeds = [b"oracle_bond", oracle.key().as_ref()],
+        bump = oracle_bond.bump
+    )]
+    pub oracle_bond: Account<'info, OracleBond>,
+
+    pub oracle: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<UnbondOracle>, amount: u64) -> Result<()> {
+    let oracle_bond = &mut ctx.accounts.oracle_bond;
+    let clock = Clock::get()?;
+
+    require!(amount > 0, ErrorCode::InvalidBondAmount);
+    require!(amount <= oracle_bond.amount, ErrorCode::UnbondExceedsBond);
+
+    oracle_bond.amount -= amount;
+    oracle_bond.unbonding_amount = oracle_bond.unbonding_amount
+        .checked_add(amount)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    oracle_bond.unbond_available_at = clock.unix_timestamp + OracleBond::UNBOND_COOLDOWN;
+
+    emit!(OracleUnbonding {
+        oracle: oracle_bond.oracle,
+        amount,
+        available_at: oracle_bond.unbond_available_at,
+    });
+
+    msg!("Unbonding {} lamports until {}", amount, oracle_bond.unbond_available_at);
+
+    Ok(())
+}
+
+#[event]
+pub struct OracleUnbonding {
+    pub oracle: Pubkey,
+    pub amount: u64,
+    pub available_at: i64,
+}
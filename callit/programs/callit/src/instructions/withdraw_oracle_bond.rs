@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+
+/// Withdraw the unbonding amount once its cooldown has elapsed
+#[derive(Accounts)]
+pub struct WithdrawOracleBond<'info> {
+    #[account(
+        mut,
+        seeds = [b"oracle_bond", oracle.key().as_ref()],
+        bump = oracle_bond.bump
+    )]
+    pub oracle_bond: Account<'info, OracleBond>,
+
+    #[account(mut)]
+    pub oracle: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<WithdrawOracleBond>) -> Result<()> {
+    let clock = Clock::get()?;
+    let amount = ctx.accounts.oracle_bond.unbonding_amount;
+
+    require!(amount > 0, ErrorCode::NothingToWithdraw);
+    require!(
+        clock.unix_timestamp >= ctx.accounts.oracle_bond.unbond_available_at,
+        ErrorCode::UnbondCooldownActive
+    );
+
+    ctx.accounts.oracle_bond.sub_lamports(amount)?;
+    ctx.accounts.oracle.add_lamports(amount)?;
+
+    let oracle_bond = &mut ctx.accounts.oracle_bond;
+    oracle_bond.unbonding_amount = 0;
+
+    emit!(OracleBondWithdrawn {
+        oracle: oracle_bond.oracle,
+        amount,
+    });
+
+    msg!("Withdrew {} lamports of oracle bond", amount);
+
+    Ok(())
+}
+
+#[event]
+pub struct OracleBondWithdrawn {
+    pub oracle: Pubkey,
+    pub amount: u64,
+}
@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::state::*;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury", config.key().as_ref()],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(mut)]
+    pub recipient: SystemAccount<'info>,
+
+    pub authority: Signer<'info>,
+
+    /// Pass to withdraw fees collected in this mint instead of lamports
+    pub mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program
+    )]
+    pub treasury_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = recipient,
+        token::token_program = token_program
+    )]
+    pub recipient_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+pub fn handler(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+    if ctx.accounts.mint.is_some() {
+        return withdraw_tokens(ctx, amount);
+    }
+
+    let treasury_info = ctx.accounts.treasury.to_account_info();
+
+    // Treasury must stay rent-exempt after the withdrawal
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(Treasury::SIZE);
+    let available = treasury_info.lamports().saturating_sub(rent_exempt_minimum);
+
+    require!(amount <= available, ErrorCode::InsufficientTreasuryBalance);
+
+    treasury_info.sub_lamports(amount)?;
+    ctx.accounts.recipient.add_lamports(amount)?;
+
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.total_withdrawn = treasury.total_withdrawn
+        .checked_add(amount)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    emit!(TreasuryWithdrawn {
+        treasury: treasury.key(),
+        recipient: ctx.accounts.recipient.key(),
+        mint: None,
+        amount,
+    });
+
+    msg!("Withdrew {} lamports from treasury", amount);
+
+    Ok(())
+}
+
+/// Token fees sit in the treasury's associated token accounts and are not
+/// tracked by its lamport counters
+fn withdraw_tokens(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+    let accounts = &ctx.accounts;
+    let (mint, treasury_token_account, recipient_token_account, token_program) = match (
+        accounts.mint.as_deref(),
+        accounts.treasury_token_account.as_deref(),
+        accounts.recipient_token_account.as_deref(),
+        accounts.token_program.as_ref(),
+    ) {
+        (Some(mint), Some(from), Some(to), Some(program)) => (mint, from, to, program),
+        _ => return Err(ErrorCode::MissingTokenAccounts.into()),
+    };
+
+    require!(
+        amount <= treasury_token_account.amount,
+        ErrorCode::InsufficientTreasuryBalance
+    );
+
+    let config_key = accounts.config.key();
+    let seeds = &[b"treasury", config_key.as_ref(), &[accounts.treasury.bump]];
+    let signer = &[&seeds[..]];
+
+    let transfer = TransferChecked {
+        from: treasury_token_account.to_account_info(),
+        mint: mint.to_account_info(),
+        to: recipient_token_account.to_account_info(),
+        authority: accounts.treasury.to_account_info(),
+    };
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            transfer,
+            signer
+        ),
+        amount,
+        mint.decimals
+    )?;
+
+    emit!(TreasuryWithdrawn {
+        treasury: accounts.treasury.key(),
+        recipient: accounts.recipient.key(),
+        mint: Some(mint.key()),
+        amount,
+    });
+
+    msg!("Withdrew {} of mint {} from treasury", amount, mint.key());
+
+    Ok(())
+}
+
+#[event]
+pub struct TreasuryWithdrawn {
+    pub treasury: Pubkey,
+    pub recipient: Pubkey,
+    pub mint: Option<Pubkey>,
+    pub amount: u64,
+}
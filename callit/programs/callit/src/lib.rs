@@ -5,9 +5,17 @@ declare_id!("3Uo8DRnQTPhf9DtfchoBBbFHn8jXKov347RpTqBp4G3A");
 pub mod state;
 pub mod instructions;
 pub mod errors;
+pub mod attestation;
+pub mod pyth;
+pub mod settlement;
+pub mod payout;
+pub mod escrow;
+pub mod remaining_accounts;
+pub mod ed25519;
 
 use instructions::*;
 use state::*;
+use attestation::*;
 
 #[program]
 pub mod callit {
@@ -15,17 +23,20 @@ pub mod callit {
 
     pub fn initialize(
         ctx: Context<Initialize>,
-        oracle_signers: [Pubkey; 3],
+        oracle_signers: Vec<Pubkey>,
+        oracle_threshold: u8,
+        cluster: Cluster,
     ) -> Result<()> {
-        instructions::initialize::handler(ctx, oracle_signers)
+        instructions::initialize::handler(ctx, oracle_signers, oracle_threshold, cluster)
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn make_call(
         ctx: Context<MakeCall>,
-        claim: String,
+        claim: Claim,
         category: CallCategory,
         token_address: Option<Pubkey>,
-        target_price: Option<i64>,
+        price_condition: Option<PriceCondition>,
         stake: u64,
         confidence: u8,
         deadline: i64,
@@ -36,7 +47,7 @@ pub mod callit {
             claim,
             category,
             token_address,
-            target_price,
+            price_condition,
             stake,
             confidence,
             deadline,
@@ -52,14 +63,157 @@ pub mod callit {
         instructions::challenge_call::handler(ctx, stake, confidence)
     }
 
-    pub fn resolve_call<'info>(
-        ctx: Context<'_, '_, 'info, 'info, ResolveCall<'info>>,
-        outcome: resolve_call::Outcome,
+    pub fn propose_resolution(
+        ctx: Context<ProposeResolution>,
+        attestation: ResolutionAttestation,
     ) -> Result<()> {
-        instructions::resolve_call::handler(ctx, outcome)
+        instructions::propose_resolution::handler(ctx, attestation)
     }
 
-    pub fn auto_refund<'info>(ctx: Context<'_, '_, 'info, 'info, AutoRefundCall<'info>>) -> Result<()> {
+    pub fn submit_attestation(
+        ctx: Context<SubmitAttestation>,
+        outcome: Outcome,
+        observed_price: Option<i64>,
+        evidence_hash: [u8; 32],
+        claim_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::submit_attestation::handler(
+            ctx,
+            outcome,
+            observed_price,
+            evidence_hash,
+            claim_hash,
+        )
+    }
+
+    pub fn finalize_from_attestations(ctx: Context<FinalizeFromAttestations>) -> Result<()> {
+        instructions::finalize_from_attestations::handler(ctx)
+    }
+
+    pub fn bond_oracle(ctx: Context<BondOracle>, amount: u64) -> Result<()> {
+        instructions::bond_oracle::handler(ctx, amount)
+    }
+
+    pub fn unbond_oracle(ctx: Context<UnbondOracle>, amount: u64) -> Result<()> {
+        instructions::unbond_oracle::handler(ctx, amount)
+    }
+
+    pub fn withdraw_oracle_bond(ctx: Context<WithdrawOracleBond>) -> Result<()> {
+        instructions::withdraw_oracle_bond::handler(ctx)
+    }
+
+    pub fn slash_oracle(ctx: Context<SlashOracle>) -> Result<()> {
+        instructions::slash_oracle::handler(ctx)
+    }
+
+    pub fn claim_oracle_rewards(ctx: Context<ClaimOracleRewards>) -> Result<()> {
+        instructions::claim_oracle_rewards::handler(ctx)
+    }
+
+    pub fn dispute_resolution(ctx: Context<DisputeResolution>) -> Result<()> {
+        instructions::dispute_resolution::handler(ctx)
+    }
+
+    pub fn rule_dispute(ctx: Context<RuleDispute>, outcome: Outcome) -> Result<()> {
+        instructions::rule_dispute::handler(ctx, outcome)
+    }
+
+    pub fn finalize_resolution<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FinalizeResolution<'info>>,
+    ) -> Result<()> {
+        instructions::finalize_resolution::handler(ctx)
+    }
+
+    pub fn resolve_with_price_feed(ctx: Context<ResolveWithPriceFeed>) -> Result<()> {
+        instructions::resolve_with_price_feed::handler(ctx)
+    }
+
+    pub fn auto_refund(ctx: Context<AutoRefundCall>) -> Result<()> {
         instructions::auto_refund::handler(ctx)
     }
+
+    pub fn cancel_call(ctx: Context<CancelCall>) -> Result<()> {
+        instructions::cancel_call::handler(ctx)
+    }
+
+    pub fn claim_payout(ctx: Context<ClaimPayout>) -> Result<()> {
+        instructions::claim_payout::handler(ctx)
+    }
+
+    pub fn close_challenge(ctx: Context<CloseChallenge>) -> Result<()> {
+        instructions::close_challenge::handler(ctx)
+    }
+
+    pub fn close_call(ctx: Context<CloseCall>) -> Result<()> {
+        instructions::close_call::handler(ctx)
+    }
+
+    pub fn close_participant_page(ctx: Context<CloseParticipantPage>) -> Result<()> {
+        instructions::close_participant_page::handler(ctx)
+    }
+
+    pub fn close_attestation(ctx: Context<CloseAttestation>) -> Result<()> {
+        instructions::close_attestation::handler(ctx)
+    }
+
+    pub fn close_attestation_tally(ctx: Context<CloseAttestationTally>) -> Result<()> {
+        instructions::close_attestation_tally::handler(ctx)
+    }
+
+    pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+        instructions::withdraw_treasury::handler(ctx, amount)
+    }
+
+    pub fn set_paused(ctx: Context<SetPaused>, is_paused: bool) -> Result<()> {
+        instructions::set_paused::handler(ctx, is_paused)
+    }
+
+    pub fn set_protocol_fee(ctx: Context<SetProtocolFee>, protocol_fee_bps: u16) -> Result<()> {
+        instructions::set_protocol_fee::handler(ctx, protocol_fee_bps)
+    }
+
+    pub fn set_unchallenged_loss_policy(
+        ctx: Context<SetUnchallengedLossPolicy>,
+        policy: UnchallengedLossPolicy,
+    ) -> Result<()> {
+        instructions::set_unchallenged_loss_policy::handler(ctx, policy)
+    }
+
+    pub fn set_min_oracle_bond(ctx: Context<SetMinOracleBond>, min_oracle_bond: u64) -> Result<()> {
+        instructions::set_min_oracle_bond::handler(ctx, min_oracle_bond)
+    }
+
+    pub fn set_oracle_reward_share(
+        ctx: Context<SetOracleRewardShare>,
+        oracle_reward_bps: u16,
+    ) -> Result<()> {
+        instructions::set_oracle_reward_share::handler(ctx, oracle_reward_bps)
+    }
+
+    pub fn propose_authority(
+        ctx: Context<ProposeAuthority>,
+        new_authority: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::propose_authority::handler(ctx, new_authority)
+    }
+
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        instructions::accept_authority::handler(ctx)
+    }
+
+    pub fn propose_oracle_set(
+        ctx: Context<ProposeOracleSet>,
+        oracle_signers: Vec<Pubkey>,
+        oracle_threshold: u8,
+    ) -> Result<()> {
+        instructions::propose_oracle_set::handler(ctx, oracle_signers, oracle_threshold)
+    }
+
+    pub fn apply_oracle_set(ctx: Context<ApplyOracleSet>) -> Result<()> {
+        instructions::apply_oracle_set::handler(ctx)
+    }
+
+    pub fn migrate_config(ctx: Context<MigrateConfig>, cluster: Cluster) -> Result<()> {
+        instructions::migrate_config::handler(ctx, cluster)
+    }
 }
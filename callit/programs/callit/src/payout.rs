@@ -0,0 +1,209 @@
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+
+// Confidence-weighted payout curve.
+//
+// Every participant puts `confidence%` of their stake at risk. The lamports
+// that change hands are the matched amount: the smaller of the caller's
+// at-risk amount and the challengers' combined at-risk amount, so neither
+// side can win more than the other side put on the line. Higher confidence
+// therefore raises both what you can lose and what you can win.
+//
+//   caller_at_risk     = stake * confidence / 100
+//   challenger_at_risk = stake_i * confidence_i / 100
+//   matched            = min(caller_at_risk, sum(challenger_at_risk))
+//
+// CallerWins:  caller gets stake + matched - fee; challenger i forfeits
+//              ceil(matched * at_risk_i / total_risk)
+// CallerLoses: caller gets stake - matched; challenger i gains
+//              floor((matched - fee) * at_risk_i / total_risk)
+//
+// Losers round up and winners round down, so the escrow can never be
+// over-drawn; the few lamports of dust stay in escrow until `close_call`.
+
+/// Lamports of `stake` put at risk at `confidence` percent
+pub fn at_risk(stake: u64, confidence: u8) -> Result<u64> {
+    let risk = (stake as u128)
+        .checked_mul(confidence as u128)
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        / 100;
+
+    Ok(risk as u64)
+}
+
+/// Lamports that change hands between the two sides
+pub fn matched_amount(caller_at_risk: u64, total_challenger_risk: u64) -> u64 {
+    std::cmp::min(caller_at_risk, total_challenger_risk)
+}
+
+pub fn caller_payout(caller_wins: bool, stake: u64, matched: u64, protocol_fee: u64) -> Result<u64> {
+    let payout = if caller_wins {
+        stake
+            .checked_add(matched)
+            .and_then(|total| total.checked_sub(protocol_fee))
+    } else {
+        stake.checked_sub(matched)
+    };
+
+    payout.ok_or(ErrorCode::ArithmeticOverflow.into())
+}
+
+pub fn challenger_payout(
+    caller_wins: bool,
+    stake: u64,
+    at_risk: u64,
+    total_challenger_risk: u64,
+    matched: u64,
+    protocol_fee: u64,
+) -> Result<u64> {
+    if total_challenger_risk == 0 {
+        return Ok(stake);
+    }
+
+    let total_risk = total_challenger_risk as u128;
+
+    if caller_wins {
+        // Forfeit is rounded up so the caller's share is always covered
+        let numerator = (matched as u128)
+            .checked_mul(at_risk as u128)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        let forfeit = numerator.div_ceil(total_risk) as u64;
+
+        stake
+            .checked_sub(forfeit)
+            .ok_or(ErrorCode::ArithmeticOverflow.into())
+    } else {
+        let net_profit = matched
+            .checked_sub(protocol_fee)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        // Proportional share (u128 intermediate to prevent overflow)
+        let share = (net_profit as u128)
+            .checked_mul(at_risk as u128)
+            .ok_or(ErrorCode::ArithmeticOverflow)?
+            / total_risk;
+
+        stake
+            .checked_add(share as u64)
+            .ok_or(ErrorCode::ArithmeticOverflow.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIDENCES: [u8; 8] = [60, 65, 70, 75, 80, 85, 90, 95];
+
+    struct Scenario {
+        caller_stake: u64,
+        caller_confidence: u8,
+        challengers: Vec<(u64, u8)>,
+        fee_bps: u64,
+    }
+
+    impl Scenario {
+        /// Total paid out (including the fee) for the given outcome
+        fn total_paid(&self, caller_wins: bool) -> u64 {
+            let caller_risk = at_risk(self.caller_stake, self.caller_confidence).unwrap();
+            let total_risk: u64 = self
+                .challengers
+                .iter()
+                .map(|(stake, confidence)| at_risk(*stake, *confidence).unwrap())
+                .sum();
+            let matched = matched_amount(caller_risk, total_risk);
+            let fee = matched * self.fee_bps / 10_000;
+
+            let mut paid = fee + caller_payout(caller_wins, self.caller_stake, matched, fee).unwrap();
+            for (stake, confidence) in &self.challengers {
+                let risk = at_risk(*stake, *confidence).unwrap();
+                paid += challenger_payout(caller_wins, *stake, risk, total_risk, matched, fee).unwrap();
+            }
+            paid
+        }
+
+        fn escrowed(&self) -> u64 {
+            self.caller_stake + self.challengers.iter().map(|(stake, _)| stake).sum::<u64>()
+        }
+    }
+
+    /// Deterministic pseudo-random scenarios (xorshift)
+    fn scenarios(count: usize) -> Vec<Scenario> {
+        let mut seed: u64 = 0x9E37_79B9_7F4A_7C15;
+        let mut next = move || {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            seed
+        };
+
+        (0..count)
+            .map(|_| {
+                let challengers = (0..(next() % 60 + 1))
+                    .map(|_| {
+                        (10_000_000 + next() % 5_000_000_000, CONFIDENCES[(next() % 8) as usize])
+                    })
+                    .collect();
+                Scenario {
+                    caller_stake: 50_000_000 + next() % 50_000_000_000,
+                    caller_confidence: CONFIDENCES[(next() % 8) as usize],
+                    challengers,
+                    fee_bps: next() % 1_001,
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn payouts_never_exceed_escrow() {
+        for scenario in scenarios(500) {
+            for caller_wins in [true, false] {
+                let paid = scenario.total_paid(caller_wins);
+                let escrowed = scenario.escrowed();
+                let dust = escrowed - paid; // panics on overdraw
+
+                // Rounding leaves at most one lamport per challenger behind
+                assert!(dust <= scenario.challengers.len() as u64);
+            }
+        }
+    }
+
+    #[test]
+    fn unmatched_risk_is_returned() {
+        // Caller risks 0.75 SOL against 0.06 SOL of challenger risk
+        let matched = matched_amount(
+            at_risk(1_000_000_000, 75).unwrap(),
+            at_risk(100_000_000, 60).unwrap(),
+        );
+        assert_eq!(matched, 60_000_000);
+        assert_eq!(caller_payout(false, 1_000_000_000, matched, 0).unwrap(), 940_000_000);
+    }
+
+    #[test]
+    fn higher_confidence_raises_upside_and_downside() {
+        let caller_risk = at_risk(10_000_000_000, 95).unwrap();
+        let others = at_risk(1_000_000_000, 60).unwrap();
+
+        let outcome_for = |confidence: u8| {
+            let risk = at_risk(1_000_000_000, confidence).unwrap();
+            let total_risk = risk + others;
+            let matched = matched_amount(caller_risk, total_risk);
+            let win = challenger_payout(false, 1_000_000_000, risk, total_risk, matched, 0).unwrap();
+            let loss = challenger_payout(true, 1_000_000_000, risk, total_risk, matched, 0).unwrap();
+            (win, loss)
+        };
+
+        let (low_win, low_loss) = outcome_for(60);
+        let (high_win, high_loss) = outcome_for(95);
+        assert!(high_win > low_win);
+        assert!(high_loss < low_loss);
+    }
+
+    #[test]
+    fn zero_challengers_returns_caller_stake() {
+        let matched = matched_amount(at_risk(50_000_000, 95).unwrap(), 0);
+        assert_eq!(matched, 0);
+        assert_eq!(caller_payout(true, 50_000_000, matched, 0).unwrap(), 50_000_000);
+        assert_eq!(caller_payout(false, 50_000_000, matched, 0).unwrap(), 50_000_000);
+    }
+}
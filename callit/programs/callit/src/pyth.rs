@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+
+// Minimal reader for Pyth pull-oracle `PriceUpdateV2` accounts. The Pyth SDK
+// crates are not usable with our toolchain, so the layout is decoded by hand.
+
+/// Pyth Solana Receiver program (owner of every PriceUpdateV2 account)
+pub const PYTH_RECEIVER_PROGRAM_ID: Pubkey = pubkey!("rec5EKMGg6MxZYaMdyBfgwp4d5rB9T1VQH5pJv5LtFJ");
+
+/// sha256("account:PriceUpdateV2")[..8]
+const PRICE_UPDATE_V2_DISCRIMINATOR: [u8; 8] = [34, 241, 35, 99, 157, 126, 244, 205];
+
+/// Maximum age of a price used at call creation
+pub const MAX_PRICE_AGE: i64 = 60;
+
+/// How long after the deadline a settlement price may be published
+pub const RESOLUTION_PRICE_WINDOW: i64 = 300; // 5 min
+
+/// Maximum confidence interval, as bps of the price
+pub const MAX_CONFIDENCE_BPS: u64 = 200; // 2%
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum VerificationLevel {
+    Partial { num_signatures: u8 },
+    Full,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PriceFeedMessage {
+    pub feed_id: [u8; 32],
+    pub price: i64,
+    pub conf: u64,
+    pub exponent: i32,
+    pub publish_time: i64,
+    pub prev_publish_time: i64,
+    pub ema_price: i64,
+    pub ema_conf: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PriceUpdateV2 {
+    pub write_authority: Pubkey,
+    pub verification_level: VerificationLevel,
+    pub price_message: PriceFeedMessage,
+    pub posted_slot: u64,
+}
+
+impl PriceUpdateV2 {
+    /// Deserialize a price update, checking owner and discriminator
+    pub fn load(account: &AccountInfo) -> Result<Self> {
+        require!(
+            *account.owner == PYTH_RECEIVER_PROGRAM_ID,
+            ErrorCode::InvalidPythAccount
+        );
+
+        let data = account.try_borrow_data()?;
+        require!(
+            data.len() > 8 && data[..8] == PRICE_UPDATE_V2_DISCRIMINATOR,
+            ErrorCode::InvalidPythAccount
+        );
+
+        PriceUpdateV2::deserialize(&mut &data[8..])
+            .map_err(|_| error!(ErrorCode::InvalidPythAccount))
+    }
+
+    /// Fully verified price for `feed_id`, rejecting stale or low-quality data
+    pub fn get_price_no_older_than(
+        &self,
+        now: i64,
+        max_age: i64,
+        feed_id: &[u8; 32],
+    ) -> Result<&PriceFeedMessage> {
+        let message = self.get_verified_price(feed_id)?;
+
+        require!(
+            message.publish_time + max_age >= now,
+            ErrorCode::PythPriceStale
+        );
+
+        Ok(message)
+    }
+
+    /// Fully verified price for `feed_id` that is the first update published
+    /// at or after `time`, no later than `time + max_delay`. Pinning the
+    /// exact update leaves no choice of print to whoever posts it.
+    pub fn get_first_price_at_or_after(
+        &self,
+        time: i64,
+        max_delay: i64,
+        feed_id: &[u8; 32],
+    ) -> Result<&PriceFeedMessage> {
+        let message = self.get_verified_price(feed_id)?;
+
+        require!(
+            message.publish_time >= time && message.publish_time <= time + max_delay,
+            ErrorCode::PythPriceOutsideWindow
+        );
+
+        // The previous update must predate `time`, or this is not the first one
+        require!(
+            message.prev_publish_time < time,
+            ErrorCode::PythPriceNotFirstAfterDeadline
+        );
+
+        Ok(message)
+    }
+
+    fn get_verified_price(&self, feed_id: &[u8; 32]) -> Result<&PriceFeedMessage> {
+        let message = &self.price_message;
+
+        require!(
+            self.verification_level == VerificationLevel::Full,
+            ErrorCode::PythPriceUnavailable
+        );
+
+        require!(message.feed_id == *feed_id, ErrorCode::PythFeedMismatch);
+
+        require!(message.price > 0, ErrorCode::PythPriceUnavailable);
+
+        // conf / price <= MAX_CONFIDENCE_BPS / 10_000
+        let conf_scaled = (message.conf as u128) * 10_000;
+        let max_conf = (message.price as u128) * (MAX_CONFIDENCE_BPS as u128);
+        require!(conf_scaled <= max_conf, ErrorCode::PythConfidenceTooWide);
+
+        Ok(message)
+    }
+}
@@ -0,0 +1,146 @@
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+
+// Validation for program PDAs passed in `remaining_accounts`.
+//
+// Anchor checks nothing about remaining accounts, so each one is checked
+// here before it is trusted: it must be owned by this program, carry the
+// expected account discriminator, live at the address its own seeds derive,
+// be writable when it will be written, and appear only once.
+
+/// A program account whose address is derived from its own data
+pub trait ProgramPda: AccountDeserialize {
+    /// Address the account's seeds and stored bump derive, if valid
+    fn derived_address(&self) -> Option<Pubkey>;
+}
+
+/// Deserialize `account` as `T` after every remaining-account check, adding
+/// it to `seen`. Any failed check returns `error`.
+pub fn load<T: ProgramPda>(
+    account: &AccountInfo,
+    writable: bool,
+    seen: &mut Vec<Pubkey>,
+    error: ErrorCode,
+) -> Result<T> {
+    if *account.owner != crate::ID
+        || (writable && !account.is_writable)
+        || seen.contains(account.key)
+    {
+        return Err(error.into());
+    }
+
+    let data = {
+        let data = account.try_borrow_data()?;
+        T::try_deserialize(&mut &data[..]).map_err(|_| Error::from(error))?
+    };
+
+    if data.derived_address() != Some(*account.key) {
+        return Err(error.into());
+    }
+
+    seen.push(*account.key);
+
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::Challenge;
+
+    const ERROR: ErrorCode = ErrorCode::InvalidChallengeAccount;
+
+    fn challenge_data(challenger: Pubkey) -> (Pubkey, Vec<u8>) {
+        let call_id = Pubkey::new_unique();
+        let (address, _) = Pubkey::find_program_address(
+            &[b"challenge", call_id.as_ref(), challenger.as_ref()],
+            &crate::ID,
+        );
+        let challenge = Challenge {
+            call_id,
+            challenger,
+            stake: 1,
+            confidence: 50,
+            created_at: 0,
+            claimed: false,
+        };
+
+        let mut data = Vec::new();
+        challenge.try_serialize(&mut data).unwrap();
+        (address, data)
+    }
+
+    fn check(
+        key: Pubkey,
+        owner: Pubkey,
+        is_writable: bool,
+        data: &mut [u8],
+        writable: bool,
+        seen: &mut Vec<Pubkey>,
+    ) -> Result<Challenge> {
+        let mut lamports = 0;
+        let account = AccountInfo::new(
+            &key, false, is_writable, &mut lamports, data, &owner, false, 0,
+        );
+        load::<Challenge>(&account, writable, seen, ERROR)
+    }
+
+    #[test]
+    fn accepts_valid_pda() {
+        let challenger = Pubkey::new_unique();
+        let (address, mut data) = challenge_data(challenger);
+        let mut seen = Vec::new();
+
+        let challenge = check(address, crate::ID, true, &mut data, true, &mut seen).unwrap();
+
+        assert_eq!(challenge.challenger, challenger);
+        assert_eq!(seen, vec![address]);
+    }
+
+    #[test]
+    fn rejects_foreign_owner() {
+        let (address, mut data) = challenge_data(Pubkey::new_unique());
+
+        let result = check(address, Pubkey::new_unique(), false, &mut data, false, &mut Vec::new());
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn rejects_read_only_when_writing() {
+        let (address, mut data) = challenge_data(Pubkey::new_unique());
+
+        assert!(check(address, crate::ID, false, &mut data, false, &mut Vec::new()).is_ok());
+        assert!(check(address, crate::ID, false, &mut data, true, &mut Vec::new()).is_err());
+    }
+
+    #[test]
+    fn rejects_duplicates() {
+        let (address, mut data) = challenge_data(Pubkey::new_unique());
+        let mut seen = Vec::new();
+
+        assert!(check(address, crate::ID, false, &mut data, false, &mut seen).is_ok());
+        assert!(check(address, crate::ID, false, &mut data, false, &mut seen).is_err());
+    }
+
+    #[test]
+    fn rejects_address_not_derived_from_data() {
+        // Valid challenge data for one challenger at another challenge's address
+        let (_, mut data) = challenge_data(Pubkey::new_unique());
+        let (other_address, _) = challenge_data(Pubkey::new_unique());
+
+        let result = check(other_address, crate::ID, false, &mut data, false, &mut Vec::new());
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn rejects_wrong_discriminator() {
+        let (address, mut data) = challenge_data(Pubkey::new_unique());
+        data[0] ^= 0xff;
+
+        let result = check(address, crate::ID, false, &mut data, false, &mut Vec::new());
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,239 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::attestation::Outcome;
+use crate::escrow::Escrow;
+use crate::payout;
+use crate::errors::ErrorCode;
+
+/// Accounts needed to record a call's outcome. Payouts are not pushed here:
+/// each participant pulls their share later with `claim_payout`.
+///
+/// For token-staked calls `escrow.token` must point at the treasury's token
+/// account, which receives the protocol fee.
+pub struct Settlement<'a, 'info> {
+    pub call: &'a mut Account<'info, Call>,
+    pub escrow: Escrow<'a, 'info>,
+    pub treasury: &'a mut Account<'info, Treasury>,
+    pub caller_profile: &'a mut Account<'info, UserProfile>,
+    pub caller_profile_bump: u8,
+}
+
+impl<'a, 'info> Settlement<'a, 'info> {
+    pub fn settle(
+        self,
+        outcome: Outcome,
+        observed_price: Option<i64>,
+        resolved_at: i64,
+    ) -> Result<()> {
+        let call_key = self.call.key();
+        self.call.resolved_at = resolved_at;
+        self.caller_profile.ensure_initialized(self.call.caller, self.caller_profile_bump);
+
+        if outcome == Outcome::Void {
+            return self.settle_void();
+        }
+
+        // Handle zero challengers case
+        if self.call.challengers_count == 0 {
+            return self.settle_with_no_challengers(outcome, observed_price);
+        }
+
+        // ============================================
+        // RECORD OUTCOME
+        // ============================================
+
+        let caller_wins = outcome == Outcome::CallerWins;
+        self.call.status = if caller_wins {
+            CallStatus::ResolvedCallerWins
+        } else {
+            CallStatus::ResolvedCallerLoses
+        };
+
+        // Protocol fee comes out of the winning side's profit
+        let matched = self.call.winning_profit()?;
+        let protocol_fee = self.call.protocol_fee_on(matched)?;
+        self.call.protocol_fee = protocol_fee;
+
+        // Caller's reputation is settled here; challengers' on claim
+        // Profile totals are in lamports; token-staked calls only count
+        // toward the win/loss record and score
+        let caller_lamports = if self.call.stake_mint.is_some() {
+            0
+        } else if caller_wins {
+            matched.saturating_sub(protocol_fee)
+        } else {
+            matched
+        };
+        self.caller_profile.record_call_result(
+            caller_wins,
+            self.call.confidence,
+            caller_lamports,
+            resolved_at
+        )?;
+
+        msg!(
+            "Call settled: caller stake {}, challenger stake {}",
+            self.call.stake,
+            self.call.total_challenger_stake
+        );
+
+        // ============================================
+        // COLLECT PROTOCOL FEE
+        // ============================================
+
+        if protocol_fee > 0 {
+            let to = self.escrow.payee(&self.treasury.to_account_info());
+            self.escrow.withdraw(&to, protocol_fee)?;
+
+            // Treasury counters track lamports; token fees accrue in the
+            // treasury's token accounts instead
+            let treasury = &mut *self.treasury;
+            if self.call.stake_mint.is_none() {
+                treasury.total_collected = treasury.total_collected
+                    .checked_add(protocol_fee)
+                    .ok_or(ErrorCode::ArithmeticOverflow)?;
+            }
+
+            emit!(FeeCollected {
+                call_id: call_key,
+                treasury: treasury.key(),
+                mint: self.call.stake_mint,
+                amount: protocol_fee,
+                fee_bps: self.call.protocol_fee_bps,
+            });
+
+            msg!("Protocol fee collected: {}", protocol_fee);
+        }
+
+        emit!(CallResolved {
+            call_id: call_key,
+            outcome,
+            observed_price,
+            resolved_at,
+        });
+
+        Ok(())
+    }
+
+    /// Oracles could not determine an outcome: no winner, no fee, and every
+    /// participant claims their full stake back
+    fn settle_void(self) -> Result<()> {
+        self.call.status = CallStatus::Voided;
+        self.call.protocol_fee = 0;
+
+        self.caller_profile.calls_refunded = self.caller_profile.calls_refunded.saturating_add(1);
+        self.caller_profile.updated_at = self.call.resolved_at;
+
+        emit!(CallVoided {
+            call_id: self.call.key(),
+            voided_at: self.call.resolved_at,
+        });
+
+        msg!("Call voided - all stakes claimable in full");
+
+        Ok(())
+    }
+
+    /// Handle case with zero challengers: nothing was matched, so the
+    /// outcome only affects reputation, unless the policy in force when the
+    /// call was made routes a losing unchallenged stake to the treasury.
+    /// The forfeit is capped at the caller's at-risk amount, the most a
+    /// challenged loser could lose.
+    fn settle_with_no_challengers(
+        self,
+        outcome: Outcome,
+        observed_price: Option<i64>,
+    ) -> Result<()> {
+        let call_key = self.call.key();
+        let resolved_at = self.call.resolved_at;
+        let caller_wins = outcome == Outcome::CallerWins;
+
+        self.call.status = if caller_wins {
+            CallStatus::ResolvedCallerWins
+        } else {
+            CallStatus::ResolvedCallerLoses
+        };
+
+        let forfeited = !caller_wins
+            && self.call.unchallenged_loss_policy == UnchallengedLossPolicy::Treasury;
+
+        // The forfeit is booked as protocol fee; the caller claims the rest
+        let forfeit = if forfeited {
+            payout::at_risk(self.call.stake, self.call.confidence)?
+        } else {
+            0
+        };
+        self.call.protocol_fee = forfeit;
+
+        let lamports = if self.call.stake_mint.is_none() { forfeit } else { 0 };
+        self.caller_profile.record_call_result(
+            caller_wins,
+            self.call.confidence,
+            lamports,
+            resolved_at
+        )?;
+
+        if forfeited {
+            let to = self.escrow.payee(&self.treasury.to_account_info());
+            self.escrow.withdraw(&to, forfeit)?;
+
+            let treasury = &mut *self.treasury;
+            if self.call.stake_mint.is_none() {
+                treasury.total_collected = treasury.total_collected
+                    .checked_add(forfeit)
+                    .ok_or(ErrorCode::ArithmeticOverflow)?;
+            }
+
+            emit!(UnchallengedStakeForfeited {
+                call_id: call_key,
+                treasury: treasury.key(),
+                mint: self.call.stake_mint,
+                amount: forfeit,
+            });
+
+            msg!("Zero challengers - at-risk stake sent to treasury");
+        } else {
+            msg!("Zero challengers - stake claimable by caller");
+        }
+
+        emit!(CallResolved {
+            call_id: call_key,
+            outcome,
+            observed_price,
+            resolved_at,
+        });
+
+        Ok(())
+    }
+}
+
+#[event]
+pub struct CallResolved {
+    pub call_id: Pubkey,
+    pub outcome: Outcome,
+    pub observed_price: Option<i64>,
+    pub resolved_at: i64,
+}
+
+#[event]
+pub struct CallVoided {
+    pub call_id: Pubkey,
+    pub voided_at: i64,
+}
+
+#[event]
+pub struct FeeCollected {
+    pub call_id: Pubkey,
+    pub treasury: Pubkey,
+    pub mint: Option<Pubkey>,
+    pub amount: u64,
+    pub fee_bps: u16,
+}
+
+#[event]
+pub struct UnchallengedStakeForfeited {
+    pub call_id: Pubkey,
+    pub treasury: Pubkey,
+    pub mint: Option<Pubkey>,
+    pub amount: u64,
+}
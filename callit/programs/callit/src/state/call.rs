@@ -1,9 +1,95 @@
 use anchor_lang::prelude::*;
+use crate::state::{Challenge, UnchallengedLossPolicy};
+use crate::payout;
+use crate::errors::ErrorCode;
 
+/// What a call predicts. Price-based categories use `token_address` for the
+/// Pyth feed ID and express prices in that feed's exponent.
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
 pub enum CallCategory {
     TokenPrice,
     RugPrediction,
+    /// Price stays within [lower, upper] until the deadline
+    PriceRange { lower: i64, upper: i64 },
+    /// Price at the deadline is at least `move_bps` away from the creation
+    /// price, in either direction
+    PercentMove { move_bps: u16 },
+    /// Market cap of the `token_address` mint exceeds `threshold` USD
+    MarketCap { threshold: u64 },
+}
+
+impl CallCategory {
+    pub const MAX_SIZE: usize = 1 + 8 + 8;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum PriceComparator {
+    AtOrAbove,
+    AtOrBelow,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum PriceTiming {
+    AtDeadline,     // Settlement price right after the deadline
+    AnyTimeBefore,  // Touched at any point before the deadline
+}
+
+/// What a TokenPrice call predicts: the price as `price * 10^expo`, which
+/// side of it the market must be on, and when
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct PriceCondition {
+    pub comparator: PriceComparator,
+    pub price: i64,
+    pub expo: i32,
+    pub timing: PriceTiming,
+}
+
+impl PriceCondition {
+    pub const SIZE: usize = 1 + 8 + 4 + 1;
+
+    /// Whether `price` (in the condition's exponent) satisfies the comparator
+    pub fn is_met(&self, price: i64) -> bool {
+        match self.comparator {
+            PriceComparator::AtOrAbove => price >= self.price,
+            PriceComparator::AtOrBelow => price <= self.price,
+        }
+    }
+}
+
+/// What the call asserts. The full criteria live off-chain in a canonical
+/// JSON spec (Arweave / IPFS); the call keeps a display title, the spec's
+/// URI and its SHA-256, which oracles bind into every attestation.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct Claim {
+    pub title: String,
+    pub uri: String,
+    pub hash: [u8; 32],
+}
+
+impl Claim {
+    pub const MAX_TITLE_LENGTH: usize = 80;
+    pub const MAX_URI_LENGTH: usize = 200;
+
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            !self.title.is_empty() && self.title.len() <= Self::MAX_TITLE_LENGTH,
+            ErrorCode::InvalidClaimLength
+        );
+
+        require!(
+            !self.uri.is_empty() && self.uri.len() <= Self::MAX_URI_LENGTH,
+            ErrorCode::InvalidClaimUri
+        );
+
+        require!(self.hash != [0; 32], ErrorCode::MissingClaimHash);
+
+        Ok(())
+    }
+
+    /// Serialized size; accounts are sized to the actual claim, not the maximum
+    pub fn space(&self) -> usize {
+        (4 + self.title.len()) + (4 + self.uri.len()) + 32
+    }
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
@@ -12,28 +98,154 @@ pub enum CallStatus {
     ResolvedCallerWins,
     ResolvedCallerLoses,
     AutoRefunded,
+    Cancelled,
+    ResolutionProposed,  // Oracle outcome inside its dispute period
+    Voided,
+}
+
+impl CallStatus {
+    /// Outcome is final: payouts may be claimed and the call closed
+    pub fn is_settled(&self) -> bool {
+        matches!(
+            self,
+            CallStatus::ResolvedCallerWins
+                | CallStatus::ResolvedCallerLoses
+                | CallStatus::AutoRefunded
+                | CallStatus::Cancelled
+                | CallStatus::Voided
+        )
+    }
+
+    /// Settled without a winner: every participant gets their stake back
+    pub fn is_refund(&self) -> bool {
+        matches!(
+            self,
+            CallStatus::AutoRefunded | CallStatus::Cancelled | CallStatus::Voided
+        )
+    }
 }
 
 #[account]
 pub struct Call {
     pub caller: Pubkey,
-    pub claim: String,
+    pub claim: Claim,
     pub category: CallCategory,
     pub token_address: Option<Pubkey>,
-    pub target_price: Option<i64>,
+    pub price_condition: Option<PriceCondition>,  // TokenPrice only
     pub creation_price: Option<i64>,
     pub stake: u64,
     pub confidence: u8,
     pub deadline: i64,
     pub created_at: i64,
     pub status: CallStatus,
-    pub challengers_count: u8,
-    pub participants: [Pubkey; 20],  // Reduced from 51 to 20 to save stack space
+    pub challengers_count: u16,  // Challengers are indexed in ParticipantPage PDAs
     pub escrow_bump: u8,
+    pub total_challenger_stake: u64,
+    pub total_challenger_risk: u64,
+    pub protocol_fee: u64,
+    pub resolved_at: i64,
+    pub claimed_count: u16,
+    pub caller_claimed: bool,
+    pub stake_mint: Option<Pubkey>,  // None = native SOL
+    pub unchallenged_loss_policy: UnchallengedLossPolicy,  // Config policy when the call was made
+    pub protocol_fee_bps: u16,  // Config fee when the call was made
+    pub oracle_reward_bps: u16,  // Config oracle share when the call was made
 }
 
 impl Call {
-    pub const MAX_CLAIM_LENGTH: usize = 280;
-    pub const MAX_PARTICIPANTS: usize = 20;  // Reduced to manage stack size
-    pub const SIZE: usize = 8 + 32 + (4 + 280) + 1 + (1 + 32) + (1 + 8) + (1 + 8) + 8 + 1 + 8 + 8 + 1 + 1 + (32 * 20) + 1;
+    pub const MAX_CHALLENGERS: u16 = 500;
+
+    /// How long after creation an unchallenged call can be cancelled. Well
+    /// short of the 24h minimum deadline, so a caller cannot wait to see the
+    /// outcome coming and withdraw a losing call.
+    pub const CANCEL_WINDOW: i64 = 3_600; // 1h
+
+    /// Size of everything but the claim
+    pub const BASE_SIZE: usize = 8 + 32 + CallCategory::MAX_SIZE + (1 + 32) + (1 + PriceCondition::SIZE) + (1 + 8) + 8 + 1 + 8 + 8 + 1 + 2 + 1 + 8 + 8 + 8 + 8 + 2 + 1 + (1 + 32) + 1 + 2 + 2;
+
+    pub fn space(claim: &Claim) -> usize {
+        Self::BASE_SIZE + claim.space()
+    }
+
+    /// Settled trustlessly from Pyth by `resolve_with_price_feed` rather
+    /// than by oracle attestation. Only conditions decided by the price at
+    /// the deadline qualify; a touch before it needs the full price history.
+    pub fn is_price_feed_resolvable(&self) -> bool {
+        match self.category {
+            CallCategory::TokenPrice => matches!(
+                self.price_condition,
+                Some(PriceCondition { timing: PriceTiming::AtDeadline, .. })
+            ),
+            CallCategory::PercentMove { .. } => true,
+            _ => false,
+        }
+    }
+
+    /// Gross profit of the winning side, before the protocol fee: the
+    /// amount matched between caller and challengers (see `payout`)
+    pub fn winning_profit(&self) -> Result<u64> {
+        Ok(payout::matched_amount(
+            payout::at_risk(self.stake, self.confidence)?,
+            self.total_challenger_risk
+        ))
+    }
+
+    /// Protocol fee owed on the winning side's profit
+    pub fn protocol_fee_on(&self, profit: u64) -> Result<u64> {
+        let fee = (profit as u128)
+            .checked_mul(self.protocol_fee_bps as u128)
+            .ok_or(ErrorCode::ArithmeticOverflow)?
+            / 10_000;
+
+        Ok(fee as u64)
+    }
+
+    /// Part of the collected protocol fee owed to the oracles that resolved the call
+    pub fn oracle_reward(&self) -> Result<u64> {
+        let reward = (self.protocol_fee as u128)
+            .checked_mul(self.oracle_reward_bps as u128)
+            .ok_or(ErrorCode::ArithmeticOverflow)?
+            / 10_000;
+
+        Ok(reward as u64)
+    }
+
+    /// Amount owed to the caller once the call is settled, in the stake currency
+    pub fn caller_payout(&self) -> Result<u64> {
+        match self.status {
+            // Unchallenged: nothing matched; a forfeited at-risk amount was
+            // already sent to the treasury as the protocol fee
+            CallStatus::ResolvedCallerWins | CallStatus::ResolvedCallerLoses
+                if self.challengers_count == 0 =>
+            {
+                self.stake
+                    .checked_sub(self.protocol_fee)
+                    .ok_or(ErrorCode::ArithmeticOverflow.into())
+            }
+            CallStatus::ResolvedCallerWins | CallStatus::ResolvedCallerLoses => payout::caller_payout(
+                self.status == CallStatus::ResolvedCallerWins,
+                self.stake,
+                self.winning_profit()?,
+                self.protocol_fee
+            ),
+            CallStatus::AutoRefunded | CallStatus::Cancelled | CallStatus::Voided => Ok(self.stake),
+            CallStatus::Active | CallStatus::ResolutionProposed => Err(ErrorCode::CallNotSettled.into()),
+        }
+    }
+
+    /// Amount owed to a challenger once the call is settled, in the stake currency
+    pub fn challenger_payout(&self, challenge: &Challenge) -> Result<u64> {
+        match self.status {
+            CallStatus::ResolvedCallerWins | CallStatus::ResolvedCallerLoses => payout::challenger_payout(
+                self.status == CallStatus::ResolvedCallerWins,
+                challenge.stake,
+                payout::at_risk(challenge.stake, challenge.confidence)?,
+                self.total_challenger_risk,
+                self.winning_profit()?,
+                self.protocol_fee
+            ),
+            CallStatus::AutoRefunded | CallStatus::Cancelled | CallStatus::Voided => Ok(challenge.stake),
+            CallStatus::Active | CallStatus::ResolutionProposed => Err(ErrorCode::CallNotSettled.into()),
+        }
+    }
 }
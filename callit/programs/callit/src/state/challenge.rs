@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::remaining_accounts::ProgramPda;
 
 #[account]
 pub struct Challenge {
@@ -7,8 +8,19 @@ pub struct Challenge {
     pub stake: u64,
     pub confidence: u8,
     pub created_at: i64,
+    pub claimed: bool,
 }
 
 impl Challenge {
-    pub const SIZE: usize = 8 + 32 + 32 + 8 + 1 + 8;
+    pub const SIZE: usize = 8 + 32 + 32 + 8 + 1 + 8 + 1;
+}
+
+impl ProgramPda for Challenge {
+    fn derived_address(&self) -> Option<Pubkey> {
+        let (address, _) = Pubkey::find_program_address(
+            &[b"challenge", self.call_id.as_ref(), self.challenger.as_ref()],
+            &crate::ID
+        );
+        Some(address)
+    }
 }
@@ -1,14 +1,96 @@
 use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+
+/// Cluster the program is deployed to, bound into every oracle attestation
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum Cluster {
+    Localnet,
+    Devnet,
+    Mainnet,
+}
+
+/// What happens to the stake of an unchallenged call that loses. Calls
+/// keep the policy in force when they were made; `Treasury` takes only the
+/// at-risk share of the stake.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum UnchallengedLossPolicy {
+    RefundCaller,
+    Treasury,
+}
 
 #[account]
 pub struct GlobalConfig {
     pub authority: Pubkey,
-    pub oracle_signers: [Pubkey; 3],
+    pub oracle_signers: Vec<Pubkey>,
+    pub oracle_threshold: u8,
     pub protocol_fee_bps: u16,
     pub is_paused: bool,
     pub bump: u8,
+    pub pending_authority: Option<Pubkey>,
+    pub pending_oracle_set: Option<PendingOracleSet>,
+    pub cluster: Cluster,
+    pub unchallenged_loss_policy: UnchallengedLossPolicy,
+    pub min_oracle_bond: u64,  // Lamports an oracle must have bonded to count
+    pub oracle_reward_bps: u16,  // Share of each resolution's fee paid to its oracles
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct PendingOracleSet {
+    pub oracle_signers: Vec<Pubkey>,
+    pub oracle_threshold: u8,
+    pub eta: i64,
+}
+
+impl PendingOracleSet {
+    pub const SIZE: usize = (4 + 32 * GlobalConfig::MAX_ORACLE_SIGNERS) + 1 + 8;
 }
 
 impl GlobalConfig {
+    pub const MAX_ORACLE_SIGNERS: usize = 10;
+    pub const SIZE: usize = 8 + 32 + (4 + 32 * Self::MAX_ORACLE_SIGNERS) + 1 + 2 + 1 + 1 + (1 + 32) + (1 + PendingOracleSet::SIZE) + 1 + 1 + 8 + 2;
+    pub const MAX_PROTOCOL_FEE_BPS: u16 = 1_000; // 10%
+    pub const ORACLE_ROTATION_DELAY: i64 = 172_800; // 48h
+    pub const DEFAULT_MIN_ORACLE_BOND: u64 = 10_000_000_000; // 10 SOL
+    pub const DEFAULT_ORACLE_REWARD_BPS: u16 = 2_000; // 20% of the fee
+
+    /// Oracle sets must contain 1..=MAX distinct, non-default keys and a
+    /// threshold that is a strict majority of the set
+    pub fn validate_oracle_set(oracle_signers: &[Pubkey], oracle_threshold: u8) -> Result<()> {
+        require!(
+            !oracle_signers.is_empty() && oracle_signers.len() <= Self::MAX_ORACLE_SIGNERS,
+            ErrorCode::InvalidOracleSet
+        );
+
+        for (i, signer) in oracle_signers.iter().enumerate() {
+            require!(*signer != Pubkey::default(), ErrorCode::InvalidOracleSet);
+            require!(
+                !oracle_signers[..i].contains(signer),
+                ErrorCode::InvalidOracleSet
+            );
+        }
+
+        let threshold = oracle_threshold as usize;
+        require!(
+            threshold <= oracle_signers.len() && threshold * 2 > oracle_signers.len(),
+            ErrorCode::InvalidOracleThreshold
+        );
+
+        Ok(())
+    }
+}
+
+/// Original (v1) config layout with a fixed 2-of-3 oracle set, kept only so
+/// `migrate_config` can read accounts created before N-of-M quorums
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct GlobalConfigV1 {
+    pub authority: Pubkey,
+    pub oracle_signers: [Pubkey; 3],
+    pub protocol_fee_bps: u16,
+    pub is_paused: bool,
+    pub bump: u8,
+}
+
+impl GlobalConfigV1 {
     pub const SIZE: usize = 8 + 32 + (32 * 3) + 2 + 1 + 1;
+    pub const ORACLE_THRESHOLD: u8 = 2;
 }
@@ -1,7 +1,21 @@
 pub mod call;
 pub mod challenge;
 pub mod config;
+pub mod treasury;
+pub mod profile;
+pub mod participants;
+pub mod resolution;
+pub mod oracle_attestation;
+pub mod oracle_bond;
+pub mod oracle_rewards;
 
 pub use call::*;
 pub use challenge::*;
 pub use config::*;
+pub use treasury::*;
+pub use profile::*;
+pub use participants::*;
+pub use resolution::*;
+pub use oracle_attestation::*;
+pub use oracle_bond::*;
+pub use oracle_rewards::*;
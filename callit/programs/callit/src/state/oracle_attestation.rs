@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use crate::attestation::Outcome;
+use crate::state::GlobalConfig;
+
+/// One oracle's verdict on a call, at `["attestation", call, oracle]`.
+/// Oracles submit independently; one attestation per oracle per call.
+#[account]
+pub struct Attestation {
+    pub call: Pubkey,
+    pub oracle: Pubkey,
+    pub outcome: Outcome,
+    pub observed_price: Option<i64>,
+    pub evidence_hash: [u8; 32],  // SHA-256 of the oracle's evidence bundle
+    pub submitted_at: i64,
+    pub bump: u8,
+}
+
+impl Attestation {
+    pub const SIZE: usize = 8 + 32 + 32 + 1 + (1 + 8) + 32 + 8 + 1;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct OracleVote {
+    pub oracle: Pubkey,
+    pub outcome: Outcome,
+    pub observed_price: Option<i64>,
+}
+
+impl OracleVote {
+    pub const SIZE: usize = 32 + 1 + (1 + 8);
+}
+
+/// Running tally of a call's attestations, at `["attestation_tally", call]`,
+/// so quorum can be checked without passing every Attestation account.
+#[account]
+pub struct AttestationTally {
+    pub call: Pubkey,
+    pub payer: Pubkey,  // Funded the tally's rent; refunded on close
+    pub votes: Vec<OracleVote>,
+    pub conflicted: bool,  // Oracles have disagreed on the outcome
+    pub bump: u8,
+}
+
+impl AttestationTally {
+    pub const MAX_VOTES: usize = GlobalConfig::MAX_ORACLE_SIGNERS;
+    pub const SIZE: usize = 8 + 32 + 32 + (4 + OracleVote::SIZE * Self::MAX_VOTES) + 1 + 1;
+
+    /// Tallies are created lazily (init_if_needed) by the first attestation
+    pub fn ensure_initialized(&mut self, call: Pubkey, payer: Pubkey, bump: u8) {
+        if self.call == Pubkey::default() {
+            self.call = call;
+            self.payer = payer;
+            self.bump = bump;
+        }
+    }
+
+    /// Record a vote, returning an earlier outcome it contradicts, if any.
+    /// Votes from oracles rotated out of the set are dropped, which keeps the
+    /// tally within the size of the current set.
+    pub fn record(&mut self, vote: OracleVote, oracle_signers: &[Pubkey]) -> Option<Outcome> {
+        self.votes.retain(|v| oracle_signers.contains(&v.oracle));
+
+        let conflicting = self
+            .votes
+            .iter()
+            .find(|v| v.outcome != vote.outcome)
+            .map(|v| v.outcome.clone());
+
+        if conflicting.is_some() {
+            self.conflicted = true;
+        }
+
+        self.votes.push(vote);
+        conflicting
+    }
+
+    /// Outcome at least `threshold` current oracles agree on, with the median
+    /// of the prices they observed and the oracles that agreed
+    pub fn quorum(
+        &self,
+        oracle_signers: &[Pubkey],
+        threshold: u8,
+    ) -> Option<(Outcome, Option<i64>, Vec<Pubkey>)> {
+        let current: Vec<&OracleVote> = self
+            .votes
+            .iter()
+            .filter(|v| oracle_signers.contains(&v.oracle))
+            .collect();
+
+        current.iter().find_map(|candidate| {
+            let agreeing: Vec<&&OracleVote> = current
+                .iter()
+                .filter(|v| v.outcome == candidate.outcome)
+                .collect();
+
+            if agreeing.len() < threshold as usize {
+                return None;
+            }
+
+            let mut prices: Vec<i64> = agreeing.iter().filter_map(|v| v.observed_price).collect();
+            prices.sort_unstable();
+            let observed_price = prices.get(prices.len().saturating_sub(1) / 2).copied();
+
+            let oracles = agreeing.iter().map(|v| v.oracle).collect();
+
+            Some((candidate.outcome.clone(), observed_price, oracles))
+        })
+    }
+}
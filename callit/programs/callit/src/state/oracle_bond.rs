@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::remaining_accounts::{self, ProgramPda};
+
+/// Lamports an oracle has at stake, held in this PDA at `["oracle_bond", oracle]`.
+/// Only oracles bonded at or above `GlobalConfig::min_oracle_bond` count
+/// towards a resolution quorum; a ruling that overturns their outcome lets
+/// anyone slash the bond to the treasury.
+#[account]
+pub struct OracleBond {
+    pub oracle: Pubkey,
+    pub amount: u64,
+    pub unbonding_amount: u64,  // Still slashable until withdrawn
+    pub unbond_available_at: i64,
+    pub slashed_total: u64,
+    pub bump: u8,
+}
+
+impl OracleBond {
+    pub const SIZE: usize = 8 + 32 + 8 + 8 + 8 + 8 + 1;
+
+    /// Outlasts a full dispute and ruling period, so an oracle cannot pull
+    /// its bond out from under a pending dispute
+    pub const UNBOND_COOLDOWN: i64 = 1_209_600; // 14 days
+
+    /// Bonds are created lazily (init_if_needed) by the oracle's first deposit
+    pub fn ensure_initialized(&mut self, oracle: Pubkey, bump: u8) {
+        if self.oracle == Pubkey::default() {
+            self.oracle = oracle;
+            self.bump = bump;
+        }
+    }
+
+    /// Everything a slash can take
+    pub fn slashable(&self) -> u64 {
+        self.amount.saturating_add(self.unbonding_amount)
+    }
+}
+
+impl ProgramPda for OracleBond {
+    fn derived_address(&self) -> Option<Pubkey> {
+        Pubkey::create_program_address(
+            &[b"oracle_bond", self.oracle.as_ref(), &[self.bump]],
+            &crate::ID
+        ).ok()
+    }
+}
+
+/// Subset of `oracles` backed by an `OracleBond` in `bond_accounts` holding at
+/// least `min_bond`. Each bond must pass the remaining-account checks, so its
+/// `oracle` field can be trusted.
+pub fn bonded_oracles(
+    bond_accounts: &[AccountInfo],
+    oracles: &[Pubkey],
+    min_bond: u64,
+) -> Result<Vec<Pubkey>> {
+    let mut seen: Vec<Pubkey> = Vec::new();
+    let mut bonded: Vec<Pubkey> = Vec::new();
+
+    for account in bond_accounts {
+        let bond: OracleBond = remaining_accounts::load(
+            account,
+            false,
+            &mut seen,
+            ErrorCode::InvalidOracleBond
+        )?;
+
+        if oracles.contains(&bond.oracle) && bond.amount >= min_bond {
+            bonded.push(bond.oracle);
+        }
+    }
+
+    Ok(bonded)
+}
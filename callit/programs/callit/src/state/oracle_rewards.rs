@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::remaining_accounts::{self, ProgramPda};
+
+/// Lamports earned by an oracle for resolutions it backed, held in this PDA
+/// at `["oracle_rewards", oracle]` until `claim_oracle_rewards`. Created
+/// alongside the oracle's bond.
+#[account]
+pub struct OracleRewards {
+    pub oracle: Pubkey,
+    pub accrued: u64,  // Unclaimed, held in this account's lamports
+    pub total_claimed: u64,
+    pub bump: u8,
+}
+
+impl OracleRewards {
+    pub const SIZE: usize = 8 + 32 + 8 + 8 + 1;
+
+    /// Reward accounts are created lazily (init_if_needed) on first bond
+    pub fn ensure_initialized(&mut self, oracle: Pubkey, bump: u8) {
+        if self.oracle == Pubkey::default() {
+            self.oracle = oracle;
+            self.bump = bump;
+        }
+    }
+}
+
+impl ProgramPda for OracleRewards {
+    fn derived_address(&self) -> Option<Pubkey> {
+        Pubkey::create_program_address(
+            &[b"oracle_rewards", self.oracle.as_ref(), &[self.bump]],
+            &crate::ID
+        ).ok()
+    }
+}
+
+/// Split `total` evenly between `oracles`, moving it from `from` into their
+/// OracleRewards accounts in `reward_accounts`. Every oracle's account must
+/// be present and pass the remaining-account checks. Returns what was
+/// credited; the rounding remainder stays with `from`.
+pub fn credit_oracle_rewards<'info>(
+    from: &AccountInfo<'info>,
+    reward_accounts: &[AccountInfo<'info>],
+    oracles: &[Pubkey],
+    total: u64,
+) -> Result<u64> {
+    if oracles.is_empty() || total == 0 {
+        return Ok(0);
+    }
+
+    let share = total / oracles.len() as u64;
+    let mut seen: Vec<Pubkey> = Vec::new();
+    let mut credited: Vec<Pubkey> = Vec::new();
+
+    for account in reward_accounts {
+        let mut rewards: OracleRewards = remaining_accounts::load(
+            account,
+            true,
+            &mut seen,
+            ErrorCode::InvalidOracleRewardsAccount
+        )?;
+
+        require!(
+            oracles.contains(&rewards.oracle),
+            ErrorCode::InvalidOracleRewardsAccount
+        );
+
+        rewards.accrued = rewards.accrued
+            .checked_add(share)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        {
+            let mut data = account.try_borrow_mut_data()?;
+            let mut writer: &mut [u8] = &mut data[..];
+            rewards.try_serialize(&mut writer)?;
+        }
+
+        from.sub_lamports(share)?;
+        account.add_lamports(share)?;
+
+        credited.push(rewards.oracle);
+    }
+
+    require!(
+        credited.len() == oracles.len(),
+        ErrorCode::MissingOracleRewardsAccounts
+    );
+
+    Ok(share * oracles.len() as u64)
+}
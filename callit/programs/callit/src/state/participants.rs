@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+
+/// One page of a call's challenger index, at `["participants", call, page]`.
+/// Pages fill in order, so clients can list every challenger by reading pages
+/// `0..=challengers_count / CAPACITY` instead of scanning program accounts.
+/// One challenge per wallet is enforced by the Challenge PDA, not this index.
+#[account]
+pub struct ParticipantPage {
+    pub call: Pubkey,
+    pub page: u16,
+    pub payer: Pubkey,  // Funded the page's rent; refunded on close
+    pub challengers: Vec<Pubkey>,
+    pub bump: u8,
+}
+
+impl ParticipantPage {
+    pub const CAPACITY: usize = 32;
+    pub const SIZE: usize = 8 + 32 + 2 + 32 + (4 + 32 * Self::CAPACITY) + 1;
+
+    /// Page holding the challenger at `challenger_index` (0-based)
+    pub fn page_for(challenger_index: u16) -> u16 {
+        challenger_index / Self::CAPACITY as u16
+    }
+
+    /// Pages are created lazily (init_if_needed) by the first challenger to land on them
+    pub fn ensure_initialized(&mut self, call: Pubkey, page: u16, payer: Pubkey, bump: u8) {
+        if self.call == Pubkey::default() {
+            self.call = call;
+            self.page = page;
+            self.payer = payer;
+            self.bump = bump;
+        }
+    }
+
+    pub fn push(&mut self, challenger: Pubkey) -> Result<()> {
+        require!(
+            self.challengers.len() < Self::CAPACITY,
+            ErrorCode::MaxParticipantsReached
+        );
+        self.challengers.push(challenger);
+        Ok(())
+    }
+}
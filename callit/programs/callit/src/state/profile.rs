@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+
+/// On-chain reputation for a wallet (CALL IT score). Caller stats update
+/// when the call settles; challenger stats, refunds included, only update
+/// when the challenger claims, so they lag until claims are made.
+#[account]
+pub struct UserProfile {
+    pub wallet: Pubkey,
+    pub calls_made: u32,
+    pub calls_won: u32,
+    pub calls_lost: u32,
+    pub calls_refunded: u32,
+    pub challenges_made: u32,
+    pub challenges_won: u32,
+    pub challenges_lost: u32,
+    pub current_streak: u32,
+    pub best_streak: u32,
+    pub lamports_won: u64,
+    pub lamports_lost: u64,
+    pub score: i64,
+    pub updated_at: i64,
+    pub bump: u8,
+    pub challenges_refunded: u32,
+    pub calls_cancelled: u32,
+}
+
+impl UserProfile {
+    pub const SIZE: usize = 8 + 32 + (4 * 9) + 8 + 8 + 8 + 8 + 1 + 4 + 4;
+    pub const MAX_STREAK_BONUS: u32 = 10;
+
+    /// Profiles are created lazily (init_if_needed); stamp owner on first use
+    pub fn ensure_initialized(&mut self, wallet: Pubkey, bump: u8) {
+        if self.wallet == Pubkey::default() {
+            self.wallet = wallet;
+            self.bump = bump;
+        }
+    }
+
+    pub fn record_call_result(&mut self, won: bool, confidence: u8, lamports: u64, now: i64) -> Result<()> {
+        if won {
+            self.calls_won = self.calls_won.saturating_add(1);
+        } else {
+            self.calls_lost = self.calls_lost.saturating_add(1);
+        }
+        self.record_result(won, confidence, lamports, now)
+    }
+
+    pub fn record_challenge_result(&mut self, won: bool, confidence: u8, lamports: u64, now: i64) -> Result<()> {
+        if won {
+            self.challenges_won = self.challenges_won.saturating_add(1);
+        } else {
+            self.challenges_lost = self.challenges_lost.saturating_add(1);
+        }
+        self.record_result(won, confidence, lamports, now)
+    }
+
+    /// Score moves by (confidence - 50) per result, so a 95% call is worth
+    /// 45 points and a 60% call 10; wins add a streak bonus of up to 10.
+    fn record_result(&mut self, won: bool, confidence: u8, lamports: u64, now: i64) -> Result<()> {
+        let weight = (confidence as i64).saturating_sub(50);
+
+        if won {
+            self.current_streak = self.current_streak.saturating_add(1);
+            self.best_streak = std::cmp::max(self.best_streak, self.current_streak);
+            self.lamports_won = self.lamports_won
+                .checked_add(lamports)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+            let streak_bonus = std::cmp::min(self.current_streak, Self::MAX_STREAK_BONUS) as i64;
+            self.score = self.score.saturating_add(weight + streak_bonus);
+        } else {
+            self.current_streak = 0;
+            self.lamports_lost = self.lamports_lost
+                .checked_add(lamports)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+            self.score = self.score.saturating_sub(weight);
+        }
+
+        self.updated_at = now;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use crate::attestation::Outcome;
+use crate::state::GlobalConfig;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum ResolutionStatus {
+    Proposed,
+    Disputed,
+    Ruled,
+}
+
+/// Oracle outcome awaiting finalization, at `["resolution", call]`. Funds stay
+/// in escrow until the dispute period passes or a dispute has been ruled on.
+#[account]
+pub struct Resolution {
+    pub call: Pubkey,
+    pub proposer: Pubkey,  // Paid the rent; refunded on finalize
+    pub outcome: Outcome,
+    pub observed_price: Option<i64>,
+    pub proposed_at: i64,
+    pub dispute_deadline: i64,
+    pub status: ResolutionStatus,
+    pub disputer: Option<Pubkey>,
+    pub disputed_at: i64,
+    pub bond: u64,  // Held in this account's lamports until finalize
+    pub ruling: Option<Outcome>,
+    pub bump: u8,
+    pub oracles: Vec<Pubkey>,  // Bonded oracles behind the outcome; removed as they are slashed
+}
+
+impl Resolution {
+    pub const DISPUTE_PERIOD: i64 = 86_400; // 24h to dispute a proposed outcome
+    pub const RULING_PERIOD: i64 = 604_800; // 7 days for the authority to rule
+    pub const DISPUTE_BOND: u64 = 500_000_000; // 0.5 SOL
+    pub const SIZE: usize = 8 + 32 + 32 + 1 + (1 + 8) + 8 + 8 + 1 + (1 + 32) + 8 + 8 + (1 + 1) + 1 + (4 + 32 * GlobalConfig::MAX_ORACLE_SIGNERS);
+
+    /// Record a freshly proposed outcome backed by `oracles` and open its
+    /// dispute period. Returns the dispute deadline.
+    pub fn open(
+        &mut self,
+        call: Pubkey,
+        proposer: Pubkey,
+        outcome: Outcome,
+        observed_price: Option<i64>,
+        oracles: Vec<Pubkey>,
+        now: i64,
+    ) -> i64 {
+        self.call = call;
+        self.proposer = proposer;
+        self.outcome = outcome;
+        self.oracles = oracles;
+        self.observed_price = observed_price;
+        self.proposed_at = now;
+        self.dispute_deadline = now + Self::DISPUTE_PERIOD;
+        self.status = ResolutionStatus::Proposed;
+        self.disputer = None;
+        self.disputed_at = 0;
+        self.bond = 0;
+        self.ruling = None;
+
+        self.dispute_deadline
+    }
+
+    /// Outcome the call settles with once finalized
+    pub fn final_outcome(&self) -> Outcome {
+        self.ruling.clone().unwrap_or_else(|| self.outcome.clone())
+    }
+
+    /// Disputer was right: the ruling overturned the proposed outcome
+    pub fn dispute_upheld(&self) -> bool {
+        matches!(&self.ruling, Some(ruling) if *ruling != self.outcome)
+    }
+}
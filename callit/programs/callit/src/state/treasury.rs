@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct Treasury {
+    pub config: Pubkey,
+    pub total_collected: u64,
+    pub total_withdrawn: u64,
+    pub bump: u8,
+    pub total_oracle_rewards: u64,  // Credited out to OracleRewards accounts
+}
+
+impl Treasury {
+    pub const SIZE: usize = 8 + 32 + 8 + 8 + 1 + 8;
+}
@@ -3,6 +3,17 @@ import { Program, BN } from "@coral-xyz/anchor";
 import { Callit } from "../target/types/callit";
 import { PublicKey, Keypair, SystemProgram, LAMPORTS_PER_SOL } from "@solana/web3.js";
 import { assert } from "chai";
+import { createHash } from "crypto";
+
+// Claim spec as stored on-chain: title, URI and SHA-256 of the canonical JSON
+function claimSpec(title: string, criteria: string) {
+  const hash = createHash("sha256").update(JSON.stringify({ title, criteria })).digest();
+  return {
+    title,
+    uri: `ar://test-${hash.toString("hex").slice(0, 16)}`,
+    hash: Array.from(hash),
+  };
+}
 
 describe("CALL IT - Phase 1 Tests", () => {
   // Configure the client to use the devnet cluster
@@ -24,6 +35,7 @@ describe("CALL IT - Phase 1 Tests", () => {
 
   let configPda: PublicKey;
   let configBump: number;
+  let treasuryPda: PublicKey;
 
   let callPda: PublicKey;
   let callBump: number;
@@ -35,6 +47,13 @@ describe("CALL IT - Phase 1 Tests", () => {
 
   const callNonce = new BN(Date.now() / 1000);
 
+  // Challengers are indexed in pages of 32 at ["participants", call, page]
+  const participantPagePda = (call: PublicKey, page: number) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("participants"), call.toBuffer(), new BN(page).toArrayLike(Buffer, "le", 2)],
+      program.programId
+    )[0];
+
   before(async () => {
     // Derive config PDA
     [configPda, configBump] = PublicKey.findProgramAddressSync(
@@ -42,6 +61,12 @@ describe("CALL IT - Phase 1 Tests", () => {
       program.programId
     );
 
+    // Derive treasury PDA
+    [treasuryPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("treasury"), configPda.toBuffer()],
+      program.programId
+    );
+
     // Airdrop SOL to test accounts
     console.log("Airdropping SOL to test accounts...");
 
@@ -71,13 +96,14 @@ describe("CALL IT - Phase 1 Tests", () => {
 
     try {
       const tx = await program.methods
-        .initialize([
-          oracle1.publicKey,
-          oracle2.publicKey,
-          oracle3.publicKey,
-        ])
+        .initialize(
+          [oracle1.publicKey, oracle2.publicKey, oracle3.publicKey],
+          2, // 2-of-3 quorum
+          { devnet: {} }
+        )
         .accounts({
           config: configPda,
+          treasury: treasuryPda,
           authority: authority.publicKey,
           systemProgram: SystemProgram.programId,
         })
@@ -90,12 +116,20 @@ describe("CALL IT - Phase 1 Tests", () => {
       assert.equal(configAccount.authority.toString(), authority.publicKey.toString());
       assert.equal(configAccount.protocolFeeBps, 500); // 5%
       assert.equal(configAccount.isPaused, false);
+      assert.equal(configAccount.oracleSigners.length, 3);
+      assert.equal(configAccount.oracleThreshold, 2);
+      assert.deepEqual(configAccount.cluster, { devnet: {} });
+
+      const treasuryAccount = await program.account.treasury.fetch(treasuryPda);
+      assert.equal(treasuryAccount.config.toString(), configPda.toString());
+      assert.equal(treasuryAccount.totalCollected.toNumber(), 0);
 
       console.log("✅ Protocol initialized successfully!");
       console.log(`   - Authority: ${configAccount.authority.toString()}`);
-      console.log(`   - Oracle 1: ${configAccount.oracleSigners[0].toString()}`);
-      console.log(`   - Oracle 2: ${configAccount.oracleSigners[1].toString()}`);
-      console.log(`   - Oracle 3: ${configAccount.oracleSigners[2].toString()}`);
+      configAccount.oracleSigners.forEach((signer, i) => {
+        console.log(`   - Oracle ${i + 1}: ${signer.toString()}`);
+      });
+      console.log(`   - Quorum: ${configAccount.oracleThreshold}-of-${configAccount.oracleSigners.length}`);
       console.log(`   - Fee: ${configAccount.protocolFeeBps} bps (5%)`);
     } catch (error) {
       console.error("❌ Initialize failed:", error);
@@ -103,7 +137,7 @@ describe("CALL IT - Phase 1 Tests", () => {
     }
   });
 
-  it("2. Make Call (Token Price Prediction)", async () => {
+  it("2. Make Call (Rug Prediction)", async () => {
     console.log("\n=== Test 2: Make Call ===");
 
     // Derive call PDA
@@ -122,7 +156,10 @@ describe("CALL IT - Phase 1 Tests", () => {
       program.programId
     );
 
-    const claim = "SOL will hit $200 by next week!";
+    const claim = claimSpec(
+      "This token will rug before next week!",
+      "Liquidity pulled or mint authority abused before the deadline"
+    );
     const stake = new BN(0.1 * LAMPORTS_PER_SOL); // 0.1 SOL
     const confidence = 75;
     const deadline = new BN(Math.floor(Date.now() / 1000) + 86400 * 2); // 2 days from now
@@ -131,9 +168,9 @@ describe("CALL IT - Phase 1 Tests", () => {
       const tx = await program.methods
         .makeCall(
           claim,
-          { tokenPrice: {} }, // Category
-          null, // token_address (None for now, Pyth integration pending)
-          null, // target_price (None for now)
+          { rugPrediction: {} }, // Category
+          Keypair.generate().publicKey, // token_address (mint under prediction)
+          null, // price_condition (TokenPrice only)
           stake,
           confidence,
           deadline,
@@ -144,6 +181,9 @@ describe("CALL IT - Phase 1 Tests", () => {
           escrow: escrowPda,
           config: configPda,
           pythPriceFeed: null,
+          stakeMint: null, // native SOL stake
+          escrowVault: null,
+          callerTokenAccount: null,
           caller: caller.publicKey,
           systemProgram: SystemProgram.programId,
         })
@@ -155,15 +195,26 @@ describe("CALL IT - Phase 1 Tests", () => {
       // Verify call was created
       const callAccount = await program.account.call.fetch(callPda);
       assert.equal(callAccount.caller.toString(), caller.publicKey.toString());
-      assert.equal(callAccount.claim, claim);
+      assert.equal(callAccount.claim.title, claim.title);
+      assert.deepEqual(callAccount.claim.hash, claim.hash);
       assert.equal(callAccount.stake.toString(), stake.toString());
+      assert.isNull(callAccount.stakeMint);
       assert.equal(callAccount.confidence, confidence);
       assert.equal(callAccount.challengersCount, 0);
 
+      // Verify reputation profile was created
+      const [callerProfilePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("profile"), caller.publicKey.toBuffer()],
+        program.programId
+      );
+      const callerProfile = await program.account.userProfile.fetch(callerProfilePda);
+      assert.equal(callerProfile.wallet.toString(), caller.publicKey.toString());
+      assert.equal(callerProfile.callsMade, 1);
+
       console.log("✅ Call created successfully!");
       console.log(`   - Call ID: ${callPda.toString()}`);
       console.log(`   - Caller: ${callAccount.caller.toString()}`);
-      console.log(`   - Claim: "${callAccount.claim}"`);
+      console.log(`   - Claim: "${callAccount.claim.title}" (${callAccount.claim.uri})`);
       console.log(`   - Stake: ${callAccount.stake.toNumber() / LAMPORTS_PER_SOL} SOL`);
       console.log(`   - Confidence: ${callAccount.confidence}%`);
       console.log(`   - Deadline: ${new Date(callAccount.deadline.toNumber() * 1000).toISOString()}`);
@@ -174,8 +225,64 @@ describe("CALL IT - Phase 1 Tests", () => {
     }
   });
 
-  it("3. Challenge Call", async () => {
-    console.log("\n=== Test 3: Challenge Call ===");
+  it("3. Test Error: Token price call requires a Pyth price update", async () => {
+    console.log("\n=== Test 3: Error Handling - Missing Pyth Account ===");
+
+    const nonce = callNonce.addn(1);
+    const [priceCallPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("call"), caller.publicKey.toBuffer(), nonce.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const [priceEscrowPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("escrow"), priceCallPda.toBuffer()],
+      program.programId
+    );
+
+    // Pyth SOL/USD feed ID, stored as the call's token_address
+    const solUsdFeedId = new PublicKey(
+      Buffer.from("ef0d8b6fda2ceba41da15d4095d1da392a0d2f8ed0c6c7bc0f4cfac8c280b56d", "hex")
+    );
+
+    try {
+      await program.methods
+        .makeCall(
+          claimSpec("SOL will hit $400 by next week!", "Pyth SOL/USD at or above $400"),
+          { tokenPrice: {} },
+          solUsdFeedId,
+          {
+            comparator: { atOrAbove: {} },
+            price: new BN(400_00000000), // $400 at the feed's 1e-8 exponent
+            expo: -8,
+            timing: { anyTimeBefore: {} },
+          },
+          new BN(0.1 * LAMPORTS_PER_SOL),
+          75,
+          new BN(Math.floor(Date.now() / 1000) + 86400 * 2),
+          nonce
+        )
+        .accounts({
+          call: priceCallPda,
+          escrow: priceEscrowPda,
+          config: configPda,
+          pythPriceFeed: null,
+          stakeMint: null, // native SOL stake
+          escrowVault: null,
+          callerTokenAccount: null,
+          caller: caller.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([caller])
+        .rpc();
+
+      assert.fail("Should have thrown error for missing Pyth account");
+    } catch (error: any) {
+      assert.include(error.message, "InvalidPythAccount");
+      console.log("✅ Correctly rejected token price call without Pyth price");
+    }
+  });
+
+  it("4. Challenge Call", async () => {
+    console.log("\n=== Test 4: Challenge Call ===");
 
     // Derive challenge PDA
     [challengePda1, challengeBump1] = PublicKey.findProgramAddressSync(
@@ -198,6 +305,7 @@ describe("CALL IT - Phase 1 Tests", () => {
           challenge: challengePda1,
           escrow: escrowPda,
           config: configPda,
+          participantPage: participantPagePda(callPda, 0),
           challenger: challenger1.publicKey,
           systemProgram: SystemProgram.programId,
         })
@@ -217,6 +325,19 @@ describe("CALL IT - Phase 1 Tests", () => {
       const callAccount = await program.account.call.fetch(callPda);
       assert.equal(callAccount.challengersCount, 1);
 
+      const page = await program.account.participantPage.fetch(participantPagePda(callPda, 0));
+      assert.deepEqual(
+        page.challengers.map((key) => key.toString()),
+        [challenger1.publicKey.toString()]
+      );
+
+      const [challengerProfilePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("profile"), challenger1.publicKey.toBuffer()],
+        program.programId
+      );
+      const challengerProfile = await program.account.userProfile.fetch(challengerProfilePda);
+      assert.equal(challengerProfile.challengesMade, 1);
+
       console.log("✅ Challenge created successfully!");
       console.log(`   - Challenge ID: ${challengePda1.toString()}`);
       console.log(`   - Challenger: ${challengeAccount.challenger.toString()}`);
@@ -229,8 +350,8 @@ describe("CALL IT - Phase 1 Tests", () => {
     }
   });
 
-  it("4. Test Error: Cannot challenge own call", async () => {
-    console.log("\n=== Test 4: Error Handling - Cannot Challenge Own Call ===");
+  it("5. Test Error: Cannot challenge own call", async () => {
+    console.log("\n=== Test 5: Error Handling - Cannot Challenge Own Call ===");
 
     const [selfChallengePda] = PublicKey.findProgramAddressSync(
       [
@@ -249,6 +370,7 @@ describe("CALL IT - Phase 1 Tests", () => {
           challenge: selfChallengePda,
           escrow: escrowPda,
           config: configPda,
+          participantPage: participantPagePda(callPda, 0),
           challenger: caller.publicKey,
           systemProgram: SystemProgram.programId,
         })
@@ -262,8 +384,8 @@ describe("CALL IT - Phase 1 Tests", () => {
     }
   });
 
-  it("5. Test Error: Duplicate challenge", async () => {
-    console.log("\n=== Test 5: Error Handling - Duplicate Challenge ===");
+  it("6. Test Error: Duplicate challenge", async () => {
+    console.log("\n=== Test 6: Error Handling - Duplicate Challenge ===");
 
     try {
       await program.methods
@@ -273,6 +395,7 @@ describe("CALL IT - Phase 1 Tests", () => {
           challenge: challengePda1,
           escrow: escrowPda,
           config: configPda,
+          participantPage: participantPagePda(callPda, 0),
           challenger: challenger1.publicKey,
           systemProgram: SystemProgram.programId,
         })
@@ -286,7 +409,466 @@ describe("CALL IT - Phase 1 Tests", () => {
     }
   });
 
-  it("6. Display Final State", async () => {
+  it("7. Cancel an unchallenged call", async () => {
+    console.log("\n=== Test 7: Cancel Unchallenged Call ===");
+
+    const nonce = callNonce.addn(2);
+    const [cancelCallPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("call"), caller.publicKey.toBuffer(), nonce.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const [cancelEscrowPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("escrow"), cancelCallPda.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .makeCall(
+        claimSpec("Changed my mind about this one", "Cancelled before any challenge"),
+        { rugPrediction: {} },
+        Keypair.generate().publicKey,
+        null,
+        new BN(0.05 * LAMPORTS_PER_SOL),
+        60,
+        new BN(Math.floor(Date.now() / 1000) + 86400 * 2),
+        nonce
+      )
+      .accounts({
+        call: cancelCallPda,
+        escrow: cancelEscrowPda,
+        config: configPda,
+        pythPriceFeed: null,
+        stakeMint: null,
+        escrowVault: null,
+        callerTokenAccount: null,
+        caller: caller.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([caller])
+      .rpc();
+
+    await program.methods
+      .cancelCall()
+      .accounts({
+        call: cancelCallPda,
+        escrow: cancelEscrowPda,
+        stakeMint: null,
+        escrowVault: null,
+        callerTokenAccount: null,
+        caller: caller.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([caller])
+      .rpc();
+
+    const callAccount = await program.account.call.fetch(cancelCallPda);
+    assert.deepEqual(callAccount.status, { cancelled: {} });
+    assert.equal(await provider.connection.getBalance(cancelEscrowPda), 0);
+    console.log("✅ Stake and escrow rent refunded, call marked cancelled");
+  });
+
+  it("8. Test Error: Cannot cancel a challenged call", async () => {
+    console.log("\n=== Test 8: Error Handling - Cancel After Challenge ===");
+
+    try {
+      await program.methods
+        .cancelCall()
+        .accounts({
+          call: callPda,
+          escrow: escrowPda,
+          stakeMint: null,
+          escrowVault: null,
+          callerTokenAccount: null,
+          caller: caller.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([caller])
+        .rpc();
+
+      assert.fail("Should have thrown error for challenged call");
+    } catch (error: any) {
+      assert.include(error.message, "CallAlreadyChallenged");
+      console.log("✅ Correctly rejected cancelling a challenged call");
+    }
+  });
+
+  it("9. Test Error: Price feed resolution before deadline", async () => {
+    console.log("\n=== Test 9: Error Handling - Early Price Feed Resolution ===");
+
+    try {
+      await program.methods
+        .resolveWithPriceFeed()
+        .accounts({
+          call: callPda,
+          escrow: escrowPda,
+          config: configPda,
+          treasury: treasuryPda,
+          pythPriceFeed: SystemProgram.programId,
+          cranker: challenger2.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([challenger2])
+        .rpc();
+
+      assert.fail("Should have thrown error before deadline");
+    } catch (error: any) {
+      assert.include(error.message, "DeadlineNotReached");
+      console.log("✅ Correctly rejected resolution before deadline");
+    }
+  });
+
+  it("10. Test Error: Dispute without a proposed resolution", async () => {
+    console.log("\n=== Test 10: Error Handling - Dispute Before Proposal ===");
+
+    const [resolutionPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("resolution"), callPda.toBuffer()],
+      program.programId
+    );
+
+    try {
+      await program.methods
+        .disputeResolution()
+        .accounts({
+          call: callPda,
+          resolution: resolutionPda,
+          challenge: challengePda1,
+          disputer: challenger1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([challenger1])
+        .rpc();
+
+      assert.fail("Should have thrown error without a proposed resolution");
+    } catch (error: any) {
+      assert.include(error.message, "AccountNotInitialized");
+      console.log("✅ Correctly rejected dispute before any proposal");
+    }
+  });
+
+  it("11. Oracle bond: bond, unbond, and early withdrawal rejected", async () => {
+    console.log("\n=== Test 11: Oracle Bond Lifecycle ===");
+
+    const [oracleBondPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("oracle_bond"), challenger2.publicKey.toBuffer()],
+      program.programId
+    );
+    const [oracleRewardsPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("oracle_rewards"), challenger2.publicKey.toBuffer()],
+      program.programId
+    );
+    const amount = new BN(0.01 * LAMPORTS_PER_SOL);
+
+    await program.methods
+      .bondOracle(amount)
+      .accounts({
+        oracleBond: oracleBondPda,
+        oracleRewards: oracleRewardsPda,
+        oracle: challenger2.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([challenger2])
+      .rpc();
+
+    await program.methods
+      .unbondOracle(amount)
+      .accounts({
+        oracleBond: oracleBondPda,
+        oracle: challenger2.publicKey,
+      })
+      .signers([challenger2])
+      .rpc();
+
+    const bond = await program.account.oracleBond.fetch(oracleBondPda);
+    assert.equal(bond.amount.toNumber(), 0);
+    assert.equal(bond.unbondingAmount.toString(), amount.toString());
+
+    try {
+      await program.methods
+        .withdrawOracleBond()
+        .accounts({
+          oracleBond: oracleBondPda,
+          oracle: challenger2.publicKey,
+        })
+        .signers([challenger2])
+        .rpc();
+
+      assert.fail("Should have thrown error during the unbonding cooldown");
+    } catch (error: any) {
+      assert.include(error.message, "UnbondCooldownActive");
+      console.log("✅ Correctly rejected withdrawal during the unbonding cooldown");
+    }
+
+    // Bonding opens an empty rewards account
+    try {
+      await program.methods
+        .claimOracleRewards()
+        .accounts({
+          oracleRewards: oracleRewardsPda,
+          oracle: challenger2.publicKey,
+        })
+        .signers([challenger2])
+        .rpc();
+
+      assert.fail("Should have thrown error with no rewards accrued");
+    } catch (error: any) {
+      assert.include(error.message, "NothingToWithdraw");
+      console.log("✅ Correctly rejected claim with no oracle rewards");
+    }
+  });
+
+  it("12. Test Error: Non-oracle cannot submit an attestation", async () => {
+    console.log("\n=== Test 12: Error Handling - Attestation From Non-Oracle ===");
+
+    const [attestationPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("attestation"), callPda.toBuffer(), challenger2.publicKey.toBuffer()],
+      program.programId
+    );
+    const [tallyPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("attestation_tally"), callPda.toBuffer()],
+      program.programId
+    );
+    const callAccount = await program.account.call.fetch(callPda);
+
+    try {
+      await program.methods
+        .submitAttestation(
+          { callerLoses: {} },
+          null,
+          Array(32).fill(1), // evidence hash
+          callAccount.claim.hash
+        )
+        .accounts({
+          call: callPda,
+          attestation: attestationPda,
+          tally: tallyPda,
+          config: configPda,
+          oracleBond: PublicKey.findProgramAddressSync(
+            [Buffer.from("oracle_bond"), challenger2.publicKey.toBuffer()],
+            program.programId
+          )[0],
+          oracle: challenger2.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([challenger2])
+        .rpc();
+
+      assert.fail("Should have thrown error for a non-oracle attestation");
+    } catch (error: any) {
+      assert.include(error.message, "UnauthorizedOracle");
+      console.log("✅ Correctly rejected attestation from a non-oracle");
+    }
+  });
+
+  it("13. Test Error: Claim before settlement", async () => {
+    console.log("\n=== Test 13: Error Handling - Early Claim ===");
+
+    try {
+      await program.methods
+        .claimPayout()
+        .accounts({
+          call: callPda,
+          escrow: escrowPda,
+          challenge: challengePda1,
+          recipient: challenger1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      assert.fail("Should have thrown error for claim on active call");
+    } catch (error: any) {
+      assert.include(error.message, "CallNotSettled");
+      console.log("✅ Correctly rejected claim before settlement");
+    }
+  });
+
+  it("14. Test Error: Close before payouts are claimed", async () => {
+    console.log("\n=== Test 14: Error Handling - Early Close ===");
+
+    try {
+      await program.methods
+        .closeChallenge()
+        .accounts({ challenge: challengePda1, challenger: challenger1.publicKey })
+        .rpc();
+
+      assert.fail("Should have thrown error for unclaimed challenge");
+    } catch (error: any) {
+      assert.include(error.message, "PayoutNotClaimed");
+      console.log("✅ Correctly rejected closing an unclaimed challenge");
+    }
+
+    try {
+      await program.methods
+        .closeCall()
+        .accounts({
+          call: callPda,
+          escrow: escrowPda,
+          caller: caller.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      assert.fail("Should have thrown error for active call");
+    } catch (error: any) {
+      assert.include(error.message, "CallNotSettled");
+      console.log("✅ Correctly rejected closing an active call");
+    }
+  });
+
+  it("15. Test Error: Non-authority cannot withdraw treasury", async () => {
+    console.log("\n=== Test 15: Error Handling - Treasury Withdrawal ===");
+
+    try {
+      await program.methods
+        .withdrawTreasury(new BN(1))
+        .accounts({
+          config: configPda,
+          treasury: treasuryPda,
+          recipient: caller.publicKey,
+          authority: caller.publicKey,
+        })
+        .signers([caller])
+        .rpc();
+
+      assert.fail("Should have thrown error for non-authority withdrawal");
+    } catch (error: any) {
+      assert.include(error.message, "Unauthorized");
+      console.log("✅ Correctly rejected non-authority withdrawal");
+      console.log(`   - Error: ${error.message || error}`);
+    }
+  });
+
+  it("16. Admin: pause and unpause protocol", async () => {
+    console.log("\n=== Test 16: Admin - Pause Protocol ===");
+
+    await program.methods
+      .setPaused(true)
+      .accounts({ config: configPda, authority: authority.publicKey })
+      .rpc();
+
+    let configAccount = await program.account.globalConfig.fetch(configPda);
+    assert.equal(configAccount.isPaused, true);
+
+    await program.methods
+      .setPaused(false)
+      .accounts({ config: configPda, authority: authority.publicKey })
+      .rpc();
+
+    configAccount = await program.account.globalConfig.fetch(configPda);
+    assert.equal(configAccount.isPaused, false);
+
+    console.log("✅ Pause toggled by authority");
+  });
+
+  it("17. Test Error: Protocol fee above maximum", async () => {
+    console.log("\n=== Test 17: Error Handling - Protocol Fee Bound ===");
+
+    try {
+      await program.methods
+        .setProtocolFee(1001)
+        .accounts({ config: configPda, authority: authority.publicKey })
+        .rpc();
+
+      assert.fail("Should have thrown error for fee above maximum");
+    } catch (error: any) {
+      assert.include(error.message, "ProtocolFeeTooHigh");
+      console.log("✅ Correctly rejected fee above 1000 bps");
+    }
+  });
+
+  it("18. Test Error: Non-authority cannot set unchallenged loss policy", async () => {
+    console.log("\n=== Test 18: Error Handling - Unchallenged Loss Policy ===");
+
+    try {
+      await program.methods
+        .setUnchallengedLossPolicy({ treasury: {} })
+        .accounts({ config: configPda, authority: caller.publicKey })
+        .signers([caller])
+        .rpc();
+
+      assert.fail("Should have thrown error for non-authority");
+    } catch (error: any) {
+      assert.include(error.message, "Unauthorized");
+      console.log("✅ Correctly rejected policy change by non-authority");
+    }
+  });
+
+  it("19. Test Error: Only the pending authority can accept", async () => {
+    console.log("\n=== Test 19: Error Handling - Authority Handover ===");
+
+    await program.methods
+      .proposeAuthority(challenger2.publicKey)
+      .accounts({ config: configPda, authority: authority.publicKey })
+      .rpc();
+
+    try {
+      await program.methods
+        .acceptAuthority()
+        .accounts({ config: configPda, newAuthority: challenger1.publicKey })
+        .signers([challenger1])
+        .rpc();
+
+      assert.fail("Should have thrown error for wrong pending authority");
+    } catch (error: any) {
+      assert.include(error.message, "NotPendingAuthority");
+      console.log("✅ Correctly rejected non-nominee");
+    }
+
+    // Cancel the handover so the rest of the suite keeps its authority
+    await program.methods
+      .proposeAuthority(null)
+      .accounts({ config: configPda, authority: authority.publicKey })
+      .rpc();
+
+    const configAccount = await program.account.globalConfig.fetch(configPda);
+    assert.isNull(configAccount.pendingAuthority);
+  });
+
+  it("20. Test Error: Oracle rotation is timelocked", async () => {
+    console.log("\n=== Test 20: Error Handling - Oracle Rotation Timelock ===");
+
+    const newOracle = Keypair.generate();
+
+    await program.methods
+      .proposeOracleSet([oracle1.publicKey, oracle2.publicKey, newOracle.publicKey], 2)
+      .accounts({ config: configPda, authority: authority.publicKey })
+      .rpc();
+
+    const configAccount = await program.account.globalConfig.fetch(configPda);
+    assert.equal(
+      configAccount.pendingOracleSet.oracleSigners[2].toString(),
+      newOracle.publicKey.toString()
+    );
+
+    try {
+      await program.methods
+        .applyOracleSet()
+        .accounts({ config: configPda, authority: authority.publicKey })
+        .rpc();
+
+      assert.fail("Should have thrown error before timelock elapsed");
+    } catch (error: any) {
+      assert.include(error.message, "OracleSetTimelockActive");
+      console.log("✅ Correctly rejected early oracle rotation");
+    }
+  });
+
+  it("21. Test Error: Oracle threshold must be a majority", async () => {
+    console.log("\n=== Test 21: Error Handling - Oracle Threshold ===");
+
+    try {
+      await program.methods
+        .proposeOracleSet([oracle1.publicKey, oracle2.publicKey, oracle3.publicKey], 1)
+        .accounts({ config: configPda, authority: authority.publicKey })
+        .rpc();
+
+      assert.fail("Should have thrown error for minority threshold");
+    } catch (error: any) {
+      assert.include(error.message, "InvalidOracleThreshold");
+      console.log("✅ Correctly rejected 1-of-3 quorum");
+    }
+  });
+
+  it("22. Display Final State", async () => {
     console.log("\n=== Final State Summary ===");
 
     const callAccount = await program.account.call.fetch(callPda);
@@ -296,7 +878,7 @@ describe("CALL IT - Phase 1 Tests", () => {
     console.log("\n📋 Call Details:");
     console.log(`   - ID: ${callPda.toString()}`);
     console.log(`   - Caller: ${callAccount.caller.toString()}`);
-    console.log(`   - Claim: "${callAccount.claim}"`);
+    console.log(`   - Claim: "${callAccount.claim.title}" (${callAccount.claim.uri})`);
     console.log(`   - Stake: ${callAccount.stake.toNumber() / LAMPORTS_PER_SOL} SOL`);
     console.log(`   - Challengers: ${callAccount.challengersCount}`);
     console.log(`   - Status: Active`);
@@ -312,8 +894,8 @@ describe("CALL IT - Phase 1 Tests", () => {
     console.log(`   - Confidence: ${challengeAccount.confidence}%`);
 
     console.log("\n✅ All Phase 1 core tests passed!");
-    console.log("\n📝 Note: resolve_call and auto_refund tests require:");
-    console.log("   - Ed25519 oracle signatures (2-of-3 multisig)");
+    console.log("\n📝 Note: propose/dispute/finalize_resolution and auto_refund tests require:");
+    console.log("   - Ed25519 oracle signatures (N-of-M quorum)");
     console.log("   - Waiting for deadline to pass");
     console.log("   - These will be tested in integration testing phase");
   });
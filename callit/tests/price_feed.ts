@@ -0,0 +1,181 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
+import { startAnchor } from "solana-bankrun";
+import { BankrunProvider } from "anchor-bankrun";
+import { Callit } from "../target/types/callit";
+import { PublicKey, Keypair, SystemProgram, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import { assert } from "chai";
+
+const IDL = require("../target/idl/callit.json");
+
+// Pyth Solana Receiver program and PriceUpdateV2 discriminator
+const PYTH_RECEIVER = new PublicKey("rec5EKMGg6MxZYaMdyBfgwp4d5rB9T1VQH5pJv5LtFJ");
+const PRICE_UPDATE_V2_DISCRIMINATOR = Buffer.from([34, 241, 35, 99, 157, 126, 244, 205]);
+
+const i64 = (value: number) => new BN(value).toTwos(64).toArrayLike(Buffer, "le", 8);
+const u64 = (value: number) => new BN(value).toArrayLike(Buffer, "le", 8);
+const i32 = (value: number) => {
+  const buf = Buffer.alloc(4);
+  buf.writeInt32LE(value);
+  return buf;
+};
+
+// Fully verified PriceUpdateV2 account data for a single price message
+function priceUpdate(
+  feedId: PublicKey,
+  price: number,
+  exponent: number,
+  publishTime: number,
+  prevPublishTime: number
+) {
+  return Buffer.concat([
+    PRICE_UPDATE_V2_DISCRIMINATOR,
+    Keypair.generate().publicKey.toBuffer(), // write_authority
+    Buffer.from([1]), // VerificationLevel::Full
+    feedId.toBuffer(),
+    i64(price),
+    u64(0), // conf
+    i32(exponent),
+    i64(publishTime),
+    i64(prevPublishTime),
+    i64(price), // ema_price
+    u64(0), // ema_conf
+    u64(0), // posted_slot
+  ]);
+}
+
+// Calls cannot be made with a past deadline, so these tests write the call
+// and price accounts straight into a bankrun bank
+describe("CALL IT - Price Feed Resolution", () => {
+  let context: Awaited<ReturnType<typeof startAnchor>>;
+  let program: Program<Callit>;
+  let authority: PublicKey;
+
+  let configPda: PublicKey;
+  let treasuryPda: PublicKey;
+
+  before(async () => {
+    context = await startAnchor(".", [], []);
+    const provider = new BankrunProvider(context);
+    anchor.setProvider(provider);
+
+    program = new Program<Callit>(IDL, provider);
+    authority = provider.wallet.publicKey;
+
+    [configPda] = PublicKey.findProgramAddressSync([Buffer.from("config")], program.programId);
+    [treasuryPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("treasury"), configPda.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .initialize([Keypair.generate().publicKey], 1, { devnet: {} })
+      .accounts({
+        config: configPda,
+        treasury: treasuryPda,
+        authority,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  });
+
+  it("1. Test Error: Rejects a price update that is not the first after the deadline", async () => {
+    console.log("\n=== Test 1: Error Handling - Later Price Update ===");
+
+    const now = Number((await context.banksClient.getClock()).unixTimestamp);
+    const deadline = now - 600;
+    const feedId = Keypair.generate().publicKey;
+    const caller = Keypair.generate().publicKey;
+
+    const callPda = Keypair.generate().publicKey;
+    const [escrowPda, escrowBump] = PublicKey.findProgramAddressSync(
+      [Buffer.from("escrow"), callPda.toBuffer()],
+      program.programId
+    );
+    const [callerProfilePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("profile"), caller.toBuffer()],
+      program.programId
+    );
+
+    const claim = { title: "SOL above $1", uri: "ar://price-feed-test", hash: new Array(32).fill(0) };
+    const stake = new BN(LAMPORTS_PER_SOL / 10);
+
+    const callData = await program.coder.accounts.encode("call", {
+      caller,
+      claim,
+      category: { tokenPrice: {} },
+      tokenAddress: feedId,
+      priceCondition: {
+        comparator: { atOrAbove: {} },
+        price: new BN(100_000_000),
+        expo: -8,
+        timing: { atDeadline: {} },
+      },
+      creationPrice: new BN(90_000_000),
+      stake,
+      confidence: 80,
+      deadline: new BN(deadline),
+      createdAt: new BN(deadline - 3600),
+      status: { active: {} },
+      challengersCount: 0,
+      escrowBump,
+      totalChallengerStake: new BN(0),
+      totalChallengerRisk: new BN(0),
+      protocolFee: new BN(0),
+      resolvedAt: new BN(0),
+      claimedCount: 0,
+      callerClaimed: false,
+      stakeMint: null,
+      unchallengedLossPolicy: { refundCaller: {} },
+      protocolFeeBps: 500,
+      oracleRewardBps: 2_000,
+    });
+
+    context.setAccount(callPda, {
+      lamports: LAMPORTS_PER_SOL,
+      data: callData,
+      owner: program.programId,
+      executable: false,
+    });
+    context.setAccount(escrowPda, {
+      lamports: stake.toNumber(),
+      data: Buffer.alloc(0),
+      owner: SystemProgram.programId,
+      executable: false,
+    });
+
+    // Published inside the window, but the previous update was also after
+    // the deadline, so this is not the first print
+    const pythPriceFeed = Keypair.generate().publicKey;
+    context.setAccount(pythPriceFeed, {
+      lamports: LAMPORTS_PER_SOL,
+      data: priceUpdate(feedId, 110_000_000, -8, deadline + 60, deadline + 30),
+      owner: PYTH_RECEIVER,
+      executable: false,
+    });
+
+    try {
+      await program.methods
+        .resolveWithPriceFeed()
+        .accounts({
+          call: callPda,
+          escrow: escrowPda,
+          config: configPda,
+          treasury: treasuryPda,
+          callerProfile: callerProfilePda,
+          stakeMint: null,
+          escrowVault: null,
+          treasuryTokenAccount: null,
+          pythPriceFeed,
+          cranker: authority,
+          systemProgram: SystemProgram.programId,
+        } as any)
+        .rpc();
+
+      assert.fail("Should have thrown error for a later price update");
+    } catch (error: any) {
+      assert.include(error.message, "PythPriceNotFirstAfterDeadline");
+      console.log("✅ Correctly rejected a price update that was not the first after the deadline");
+    }
+  });
+});
@@ -235,19 +235,13 @@ class OracleNode {
     // Signature request from peer
     this.app.post('/api/sign', async (req, res) => {
       try {
-        const { callId, outcome, timestamp } = req.body;
-        const signature = await this.coordinator.handleSignatureRequest(
-          callId,
-          outcome,
-          timestamp
-        );
+        const { attestation } = req.body;
+        const signature = await this.coordinator.handleSignatureRequest(attestation);
 
         res.json({
           signature: {
             signer: signature.signer.toString(),
             signature: Array.from(signature.signature),
-            outcome: signature.outcome,
-            timestamp: signature.timestamp,
           },
         });
       } catch (error: any) {
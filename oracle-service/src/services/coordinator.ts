@@ -1,7 +1,14 @@
 import { Keypair, PublicKey } from '@solana/web3.js';
 import * as ed25519 from '@noble/ed25519';
 import axios from 'axios';
-import { CallData, OracleSignature, ValidationResult } from '../types/index.js';
+import {
+  CallData,
+  Cluster,
+  OracleSignature,
+  ResolutionAttestation,
+  ValidationResult,
+} from '../types/index.js';
+import { attestationMessage, buildAttestation } from '../utils/attestation.js';
 import { PriceOracle } from './priceOracle.js';
 import { RugDetector } from './rugDetector.js';
 
@@ -50,24 +57,22 @@ export class OracleCoordinator {
 
     console.log(`✅ Consensus reached: ${consensus.outcome}`);
 
-    // Step 4: Generate Ed25519 signatures from all agreeing oracles
-    const timestamp = Math.floor(Date.now() / 1000);
+    // Step 4: Every agreeing oracle signs the same attestation
+    const attestation = buildAttestation(
+      call,
+      consensus.outcome,
+      Math.floor(Date.now() / 1000),
+      process.env.PROGRAM_ID!,
+      (process.env.CLUSTER || 'Devnet') as Cluster
+    );
     const signatures: OracleSignature[] = [];
 
     // My signature
-    const mySignature = await this.generateSignature(
-      call.onchainId,
-      consensus.outcome,
-      timestamp
-    );
+    const mySignature = await this.generateSignature(attestation);
     signatures.push(mySignature);
 
     // Request signatures from peers that agreed
-    const peerSignatures = await this.requestPeerSignatures(
-      call,
-      consensus.outcome,
-      timestamp
-    );
+    const peerSignatures = await this.requestPeerSignatures(attestation);
 
     signatures.push(...peerSignatures);
 
@@ -198,32 +203,21 @@ export class OracleCoordinator {
   }
 
   /**
-   * Generate Ed25519 signature for resolution
+   * Generate Ed25519 signature over the attestation message
    */
   private async generateSignature(
-    callId: string,
-    outcome: 'CallerWins' | 'CallerLoses',
-    timestamp: number
+    attestation: ResolutionAttestation
   ): Promise<OracleSignature> {
-    // Create message to sign
-    const callPubkey = new PublicKey(callId);
-    const message = Buffer.concat([
-      callPubkey.toBuffer(),
-      Buffer.from([outcome === 'CallerWins' ? 1 : 0]),
-      Buffer.from(new BigInt64Array([BigInt(timestamp)]).buffer),
-    ]);
-
     // Sign with Ed25519
     const signature = await ed25519.signAsync(
-      message,
+      attestationMessage(attestation),
       this.keypair.secretKey.slice(0, 32)
     );
 
     return {
       signer: this.keypair.publicKey,
       signature: new Uint8Array(signature),
-      outcome,
-      timestamp,
+      attestation,
     };
   }
 
@@ -231,9 +225,7 @@ export class OracleCoordinator {
    * Request signatures from peer oracles
    */
   private async requestPeerSignatures(
-    call: CallData,
-    outcome: 'CallerWins' | 'CallerLoses',
-    timestamp: number
+    attestation: ResolutionAttestation
   ): Promise<OracleSignature[]> {
     const signatures: OracleSignature[] = [];
 
@@ -241,7 +233,7 @@ export class OracleCoordinator {
       try {
         const response = await axios.post(
           `${peerUrl}/api/sign`,
-          { callId: call.onchainId, outcome, timestamp },
+          { attestation },
           { timeout: 10000 }
         );
 
@@ -250,8 +242,7 @@ export class OracleCoordinator {
           signatures.push({
             signer: new PublicKey(sig.signer),
             signature: new Uint8Array(sig.signature),
-            outcome: sig.outcome,
-            timestamp: sig.timestamp,
+            attestation,
           });
         }
       } catch (error) {
@@ -273,10 +264,8 @@ export class OracleCoordinator {
    * Handle signature request from peer (API endpoint handler)
    */
   async handleSignatureRequest(
-    callId: string,
-    outcome: 'CallerWins' | 'CallerLoses',
-    timestamp: number
+    attestation: ResolutionAttestation
   ): Promise<OracleSignature> {
-    return await this.generateSignature(callId, outcome, timestamp);
+    return await this.generateSignature(attestation);
   }
 }
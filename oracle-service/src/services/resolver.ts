@@ -9,7 +9,7 @@ import {
 } from '@solana/web3.js';
 import { Program, AnchorProvider, Wallet } from '@coral-xyz/anchor';
 import { CallData, OracleSignature } from '../types/index.js';
-import axios from 'axios';
+import { attestationMessage } from '../utils/attestation.js';
 
 /**
  * Blockchain Resolver Service
@@ -36,43 +36,19 @@ export class BlockchainResolver {
     try {
       console.log(`📤 Submitting resolution for call ${call.id}...`);
 
-      // Load all challenge PDAs
-      const challengePDAs = await this.loadChallengePDAs(call.onchainId);
-
-      // Build remaining_accounts: [challenges, wallets, caller]
-      const challengeAccounts = challengePDAs.map(c => ({
-        pubkey: c.pda,
+      // Build remaining_accounts: each signer's OracleBond PDA, read-only.
+      // Only sufficiently bonded signers count towards the quorum.
+      const remainingAccounts = oracleSignatures.map(sig => ({
+        pubkey: this.oracleBondPDA(sig.signer),
         isSigner: false,
         isWritable: false,
       }));
 
-      const challengerWallets = challengePDAs.map(c => ({
-        pubkey: c.walletPubkey,
-        isSigner: false,
-        isWritable: true,
-      }));
-
-      const callerWallet = {
-        pubkey: new PublicKey(call.callerAddress),
-        isSigner: false,
-        isWritable: true,
-      };
-
-      const remainingAccounts = [
-        ...challengeAccounts,
-        ...challengerWallets,
-        callerWallet,
-      ];
-
       // Create Ed25519 SigVerify instructions
       const ed25519Instructions: TransactionInstruction[] = [];
 
       for (const sig of oracleSignatures) {
-        const message = this.createResolutionMessage(
-          call.onchainId,
-          sig.outcome,
-          sig.timestamp
-        );
+        const message = attestationMessage(sig.attestation);
 
         const ed25519Ix = Ed25519Program.createInstructionWithPublicKey({
           publicKey: sig.signer.toBytes(),
@@ -88,11 +64,11 @@ export class BlockchainResolver {
         units: 800_000,
       });
 
-      // Note: For actual deployment, we'd build the resolve_call instruction using Anchor
+      // Note: For actual deployment, we'd build the propose_resolution instruction using Anchor
       // For now, this is a placeholder showing the structure
 
       console.log(`✅ Resolution transaction prepared`);
-      console.log(`   - Challenges: ${challengePDAs.length}`);
+      console.log(`   - Oracle bonds: ${remainingAccounts.length}`);
       console.log(`   - Oracle signatures: ${oracleSignatures.length}`);
 
       // In production, send the actual transaction here
@@ -112,39 +88,13 @@ export class BlockchainResolver {
   }
 
   /**
-   * Create resolution message for Ed25519 signing
+   * OracleBond PDA at ["oracle_bond", oracle]
    */
-  private createResolutionMessage(
-    callId: string,
-    outcome: string,
-    timestamp: number
-  ): Uint8Array {
-    const callPubkey = new PublicKey(callId);
-    const message = Buffer.concat([
-      callPubkey.toBuffer(),
-      Buffer.from([outcome === 'CallerWins' ? 1 : 0]),
-      Buffer.from(new BigInt64Array([BigInt(timestamp)]).buffer),
-    ]);
-    return new Uint8Array(message);
-  }
-
-  /**
-   * Load all challenge PDAs for a call
-   */
-  private async loadChallengePDAs(callId: string): Promise<any[]> {
-    // Query backend API for challenges
-    const backendUrl = process.env.BACKEND_URL || 'http://localhost:4000';
-
-    try {
-      const response = await axios.get(`${backendUrl}/api/calls/${callId}/challenges`);
-      return response.data.challenges.map((c: any) => ({
-        pda: new PublicKey(c.onchainId),
-        walletPubkey: new PublicKey(c.challengerAddress),
-        stake: BigInt(c.stake),
-      }));
-    } catch (error) {
-      console.error('Failed to load challenges:', error);
-      return [];
-    }
+  private oracleBondPDA(oracle: PublicKey): PublicKey {
+    const [pda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('oracle_bond'), oracle.toBuffer()],
+      this.programId
+    );
+    return pda;
   }
 }
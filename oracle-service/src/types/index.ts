@@ -16,6 +16,47 @@ export interface CallData {
   createdAt: number;
   status: 'Active' | 'ResolvedCallerWins' | 'ResolvedCallerLoses' | 'AutoRefunded';
   challengersCount: number;
+  // As stored on the Call account; bound into every resolution attestation
+  claimHash: string; // hex SHA-256 of the claim spec
+  onchainCategory: CallCategory;
+  priceCondition: PriceCondition | null;
+}
+
+export type Cluster = 'Localnet' | 'Devnet' | 'Mainnet';
+
+export type Outcome = 'CallerWins' | 'CallerLoses' | 'Void';
+
+// i64 / u64 values are decimal strings so they survive JSON between peers
+export type CallCategory =
+  | { kind: 'TokenPrice' }
+  | { kind: 'RugPrediction' }
+  | { kind: 'PriceRange'; lower: string; upper: string }
+  | { kind: 'PercentMove'; moveBps: number }
+  | { kind: 'MarketCap'; threshold: string };
+
+export interface PriceCondition {
+  comparator: 'AtOrAbove' | 'AtOrBelow';
+  price: string;
+  expo: number;
+  timing: 'AtDeadline' | 'AnyTimeBefore';
+}
+
+/**
+ * Statement each oracle signs to resolve a call; mirrors the program's
+ * `ResolutionAttestation`
+ */
+export interface ResolutionAttestation {
+  version: number;
+  programId: string;
+  cluster: Cluster;
+  call: string;
+  claimHash: string;
+  category: CallCategory;
+  priceCondition: PriceCondition | null;
+  outcome: Outcome;
+  observedPrice: string | null;
+  issuedAt: number;
+  expiresAt: number;
 }
 
 export interface ChallengeData {
@@ -32,8 +73,7 @@ export interface ChallengeData {
 export interface OracleSignature {
   signer: PublicKey;
   signature: Uint8Array;
-  outcome: 'CallerWins' | 'CallerLoses';
-  timestamp: number;
+  attestation: ResolutionAttestation;
 }
 
 export interface ValidationResult {
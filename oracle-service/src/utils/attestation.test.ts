@@ -0,0 +1,44 @@
+import { test } from 'node:test';
+import assert from 'node:assert/strict';
+import { PublicKey } from '@solana/web3.js';
+import { attestationMessage, ATTESTATION_VERSION } from './attestation.js';
+
+// Shared with `attestation::tests` in the program; both sides must produce
+// these exact bytes
+const VECTOR_HEX = [
+  '43414c4c49545f5245534f4c5554494f4e', // domain
+  '04', // version
+  '24d6356dade4db10c36ae2387a4397fd110e1fef4caaed3f5c4da21c4caf1e41', // program_id
+  '01', // cluster
+  '0101010101010101010101010101010101010101010101010101010101010101', // call
+  '0202020202020202020202020202020202020202020202020202020202020202', // claim_hash
+  '02fbffffffffffffff40420f0000000000', // category
+  '010115cd5b0700000000f8ffffff01', // price_condition
+  '01', // outcome
+  '01d6ffffffffffffff', // observed_price
+  '00f1536500000000', // issued_at
+  '58f3536500000000', // expires_at
+].join('');
+
+test('attestation message matches the program encoding', () => {
+  const message = attestationMessage({
+    version: ATTESTATION_VERSION,
+    programId: '3Uo8DRnQTPhf9DtfchoBBbFHn8jXKov347RpTqBp4G3A',
+    cluster: 'Devnet',
+    call: new PublicKey(Buffer.alloc(32, 1)).toBase58(),
+    claimHash: '02'.repeat(32),
+    category: { kind: 'PriceRange', lower: '-5', upper: '1000000' },
+    priceCondition: {
+      comparator: 'AtOrBelow',
+      price: '123456789',
+      expo: -8,
+      timing: 'AnyTimeBefore',
+    },
+    outcome: 'CallerLoses',
+    observedPrice: '-42',
+    issuedAt: 1_700_000_000,
+    expiresAt: 1_700_000_600,
+  });
+
+  assert.equal(message.toString('hex'), VECTOR_HEX);
+});
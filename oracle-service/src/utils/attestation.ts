@@ -0,0 +1,134 @@
+import { PublicKey } from '@solana/web3.js';
+import {
+  CallCategory,
+  CallData,
+  Cluster,
+  Outcome,
+  PriceCondition,
+  ResolutionAttestation,
+} from '../types/index.js';
+
+/**
+ * Resolution attestation encoding
+ * Mirrors `ResolutionAttestation::message()` in the program:
+ * "CALLIT_RESOLUTION" || borsh(ResolutionAttestation)
+ */
+
+export const ATTESTATION_DOMAIN = Buffer.from('CALLIT_RESOLUTION');
+export const ATTESTATION_VERSION = 4;
+
+// Validity window given to each attestation (program allows up to 1h)
+export const ATTESTATION_TTL = 600;
+
+const CLUSTERS: Cluster[] = ['Localnet', 'Devnet', 'Mainnet'];
+const OUTCOMES: Outcome[] = ['CallerWins', 'CallerLoses', 'Void'];
+
+const u8 = (value: number) => Buffer.from([value]);
+
+const u16 = (value: number) => {
+  const buf = Buffer.alloc(2);
+  buf.writeUInt16LE(value);
+  return buf;
+};
+
+const i32 = (value: number) => {
+  const buf = Buffer.alloc(4);
+  buf.writeInt32LE(value);
+  return buf;
+};
+
+const i64 = (value: string | number) => {
+  const buf = Buffer.alloc(8);
+  buf.writeBigInt64LE(BigInt(value));
+  return buf;
+};
+
+const u64 = (value: string | number) => {
+  const buf = Buffer.alloc(8);
+  buf.writeBigUInt64LE(BigInt(value));
+  return buf;
+};
+
+const bytes32 = (hex: string) => {
+  const buf = Buffer.from(hex, 'hex');
+  if (buf.length !== 32) {
+    throw new Error(`Expected 32 bytes, got ${buf.length}`);
+  }
+  return buf;
+};
+
+function encodeCategory(category: CallCategory): Buffer {
+  switch (category.kind) {
+    case 'TokenPrice':
+      return u8(0);
+    case 'RugPrediction':
+      return u8(1);
+    case 'PriceRange':
+      return Buffer.concat([u8(2), i64(category.lower), i64(category.upper)]);
+    case 'PercentMove':
+      return Buffer.concat([u8(3), u16(category.moveBps)]);
+    case 'MarketCap':
+      return Buffer.concat([u8(4), u64(category.threshold)]);
+  }
+}
+
+function encodePriceCondition(condition: PriceCondition | null): Buffer {
+  if (!condition) {
+    return u8(0);
+  }
+
+  return Buffer.concat([
+    u8(1),
+    u8(condition.comparator === 'AtOrAbove' ? 0 : 1),
+    i64(condition.price),
+    i32(condition.expo),
+    u8(condition.timing === 'AtDeadline' ? 0 : 1),
+  ]);
+}
+
+/**
+ * Exact bytes each oracle signs for an attestation
+ */
+export function attestationMessage(attestation: ResolutionAttestation): Buffer {
+  return Buffer.concat([
+    ATTESTATION_DOMAIN,
+    u8(attestation.version),
+    new PublicKey(attestation.programId).toBuffer(),
+    u8(CLUSTERS.indexOf(attestation.cluster)),
+    new PublicKey(attestation.call).toBuffer(),
+    bytes32(attestation.claimHash),
+    encodeCategory(attestation.category),
+    encodePriceCondition(attestation.priceCondition),
+    u8(OUTCOMES.indexOf(attestation.outcome)),
+    attestation.observedPrice === null
+      ? u8(0)
+      : Buffer.concat([u8(1), i64(attestation.observedPrice)]),
+    i64(attestation.issuedAt),
+    i64(attestation.expiresAt),
+  ]);
+}
+
+/**
+ * Attestation for `call` resolving to `outcome`, issued at `issuedAt`
+ */
+export function buildAttestation(
+  call: CallData,
+  outcome: Outcome,
+  issuedAt: number,
+  programId: string,
+  cluster: Cluster
+): ResolutionAttestation {
+  return {
+    version: ATTESTATION_VERSION,
+    programId,
+    cluster,
+    call: call.onchainId,
+    claimHash: call.claimHash,
+    category: call.onchainCategory,
+    priceCondition: call.priceCondition,
+    outcome,
+    observedPrice: null,
+    issuedAt,
+    expiresAt: issuedAt + ATTESTATION_TTL,
+  };
+}